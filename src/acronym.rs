@@ -0,0 +1,161 @@
+use alloc::string::String;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::boundary::Words;
+use crate::locale::{is_titlecase, Locale};
+
+/// Initialisms that are recognized by a [`Converter`](crate::Converter) when applying
+/// an [`AcronymStyle`].  This is the list used by Go's linters.
+pub const DEFAULT_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IO",
+    "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS",
+    "TTL", "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+/// How initialisms are written when a pattern capitalizes them.
+///
+/// Patterns like [`capital`](crate::pattern::capital) and [`camel`](crate::pattern::camel)
+/// lowercase all but the first letter of a word, which is the Rust convention.  Other
+/// languages keep some or all initialisms in uppercase.  Words that a pattern does not
+/// capitalize, like the first word in camel case, are never changed.
+/// ```
+/// use convert_case::{AcronymStyle, Case, Converter};
+///
+/// let conv = Converter::new().to_case(Case::Pascal);
+/// assert_eq!("HttpServer", conv.convert("http_server"));
+///
+/// let go = Converter::new()
+///     .to_case(Case::Camel)
+///     .set_acronym_style(AcronymStyle::Upper);
+/// assert_eq!("httpServerID", go.convert("http_server_id"));
+///
+/// let dotnet = Converter::new()
+///     .to_case(Case::Pascal)
+///     .set_acronym_style(AcronymStyle::TwoLetterUpper);
+/// assert_eq!("IOStream", dotnet.convert("io_stream"));
+/// assert_eq!("XmlReader", dotnet.convert("xml_reader"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AcronymStyle {
+    /// Initialisms are capitalized like any other word, as in `HttpServer`.
+    #[default]
+    Capitalized,
+    /// Initialisms are uppercase, as in `HTTPServer`.
+    Upper,
+    /// Initialisms of two letters are uppercase and longer initialisms are capitalized,
+    /// as in `IOStream` and `XmlReader`.
+    TwoLetterUpper,
+}
+
+impl AcronymStyle {
+    /// Rewrites the capitalized words that are initialisms according to this style,
+    /// uppercasing them with the case mappings of `locale`.
+    pub(crate) fn apply(self, words: &mut [String], initialisms: Words, locale: Locale) {
+        if self == AcronymStyle::Capitalized {
+            return;
+        }
+        for word in words.iter_mut() {
+            if self.uppercases(word, initialisms) && is_capital(word) {
+                *word = locale.uppercase(word);
+            }
+        }
+    }
+
+    /// Whether `word` is an initialism that this style writes in uppercase when it is
+    /// capitalized.
+    pub(crate) fn uppercases(self, word: &str, initialisms: Words) -> bool {
+        if self == AcronymStyle::Capitalized {
+            return false;
+        }
+        let Some(initialism) = initialisms.iter().find(|i| eq_ignore_case(word, i)) else {
+            return false;
+        };
+        match self {
+            AcronymStyle::Capitalized => false,
+            AcronymStyle::Upper => true,
+            AcronymStyle::TwoLetterUpper => initialism.graphemes(true).count() == 2,
+        }
+    }
+}
+
+/// Compares ignoring case in any locale, so the dotted and dotless `i` of Turkish and
+/// Azerbaijani match the `i` of an initialism.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    fn fold(s: &str) -> impl Iterator<Item = char> + '_ {
+        s.chars()
+            .flat_map(char::to_lowercase)
+            .filter(|&c| c != '\u{307}')
+            .map(|c| if c == 'ı' { 'i' } else { c })
+    }
+    fold(a).eq(fold(b))
+}
+
+fn is_capital(word: &str) -> bool {
+    let mut graphemes = word.graphemes(true);
+    let first = graphemes.next().unwrap_or_default();
+    let rest = graphemes.as_str();
+    first.chars().any(|c| c.is_uppercase() || is_titlecase(c))
+        && rest.chars().flat_map(char::to_lowercase).eq(rest.chars())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    fn apply(style: AcronymStyle, words: &[&str]) -> Vec<String> {
+        let mut words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        style.apply(
+            &mut words,
+            Words::Borrowed(DEFAULT_INITIALISMS),
+            Locale::Root,
+        );
+        words
+    }
+
+    #[test]
+    fn uppercase_follows_locale() {
+        let mut words = vec!["user".to_string(), "İd".to_string(), "Io".to_string()];
+        AcronymStyle::Upper.apply(
+            &mut words,
+            Words::Borrowed(DEFAULT_INITIALISMS),
+            Locale::Turkish,
+        );
+        assert_eq!(vec!["user", "İD", "IO"], words);
+    }
+
+    #[test]
+    fn capitalized_is_unchanged() {
+        assert_eq!(
+            vec!["Http", "Server"],
+            apply(AcronymStyle::Capitalized, &["Http", "Server"])
+        );
+    }
+
+    #[test]
+    fn upper() {
+        assert_eq!(
+            vec!["user", "ID", "URL"],
+            apply(AcronymStyle::Upper, &["user", "Id", "Url"])
+        );
+    }
+
+    #[test]
+    fn two_letter_upper() {
+        assert_eq!(
+            vec!["IO", "Xml", "Reader"],
+            apply(AcronymStyle::TwoLetterUpper, &["Io", "Xml", "Reader"])
+        );
+    }
+
+    #[test]
+    fn only_capitalized_words() {
+        assert_eq!(
+            vec!["http", "HTTP", "hTTP"],
+            apply(AcronymStyle::Upper, &["http", "HTTP", "hTTP"])
+        );
+    }
+}
@@ -1,17 +1,99 @@
-use unicode_segmentation::UnicodeSegmentation;
+use crate::locale::is_titlecase;
 
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut, Range};
+
+// The conditions of the boundary constants are never inlined, so each has a single
+// address that serialization can compare against
+#[inline(never)]
+fn is_underscore(s: &[&str]) -> bool {
+    s.first() == Some(&"_")
+}
+
+#[inline(never)]
+fn is_hyphen(s: &[&str]) -> bool {
+    s.first() == Some(&"-")
+}
+
+#[inline(never)]
+fn is_space(s: &[&str]) -> bool {
+    s.first() == Some(&" ")
+}
+
+#[inline(never)]
+fn is_lower_upper(s: &[&str]) -> bool {
+    s.first()
+        .is_some_and(|c| grapheme_is_lowercase(c) || grapheme_is_titlecase(c))
+        && s.get(1)
+            .is_some_and(|c| grapheme_is_uppercase(c) || grapheme_is_titlecase(c))
+}
+
+#[inline(never)]
+fn is_upper_lower(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_uppercase) == Some(true)
+        && s.get(1).map(grapheme_is_lowercase) == Some(true)
+}
+
+#[inline(never)]
+fn is_acronym(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_uppercase) == Some(true)
+        && (s.get(1).map(grapheme_is_titlecase) == Some(true)
+            || s.get(1).map(grapheme_is_uppercase) == Some(true)
+                && s.get(2).map(grapheme_is_lowercase) == Some(true))
+}
+
+#[inline(never)]
+fn is_lower_digit(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_lowercase) == Some(true)
+        && s.get(1).map(grapheme_is_digit) == Some(true)
+}
+
+#[inline(never)]
+fn is_upper_digit(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_uppercase) == Some(true)
+        && s.get(1).map(grapheme_is_digit) == Some(true)
+}
+
+#[inline(never)]
+fn is_digit_lower(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_digit) == Some(true)
+        && s.get(1).map(grapheme_is_lowercase) == Some(true)
+}
+
+#[inline(never)]
+fn is_digit_upper(s: &[&str]) -> bool {
+    s.first().map(grapheme_is_digit) == Some(true)
+        && s.get(1).map(grapheme_is_uppercase) == Some(true)
+}
 
 fn grapheme_is_digit(c: &&str) -> bool {
     c.chars().all(|c| c.is_ascii_digit())
 }
 
+// Case mappings are compared character by character, so checking a grapheme never allocates
 fn grapheme_is_uppercase(c: &&str) -> bool {
-    c.to_uppercase() != c.to_lowercase() && *c == c.to_uppercase()
+    let upper = || c.chars().flat_map(char::to_uppercase);
+    let lower = || c.chars().flat_map(char::to_lowercase);
+    !upper().eq(lower()) && c.chars().eq(upper())
 }
 
 fn grapheme_is_lowercase(c: &&str) -> bool {
-    c.to_uppercase() != c.to_lowercase() && *c == c.to_lowercase()
+    let upper = || c.chars().flat_map(char::to_uppercase);
+    let lower = || c.chars().flat_map(char::to_lowercase);
+    !upper().eq(lower()) && c.chars().eq(lower())
+}
+
+/// Titlecase letters like `ǅ` are an uppercase letter followed by a lowercase one.
+fn grapheme_is_titlecase(c: &&str) -> bool {
+    c.chars().next().is_some_and(is_titlecase)
 }
 
 /// Conditions for splitting an identifier into words.
@@ -41,29 +123,151 @@ fn grapheme_is_lowercase(c: &&str) -> bool {
 ///     .to_case(Case::Title);
 /// assert_eq!("7empest By Tool", conv.convert("7empest byTool"));
 /// ```
-#[derive(Debug, Eq, Hash, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Boundary {
     /// A unique name used for comparison.
-    pub name: &'static str,
-    /// A function that determines if this boundary is present at the start
-    /// of the string.  Second argument is the `arg` field.
-    pub condition: fn(&[&str], Option<&'static str>) -> bool,
-    /// An optional string passed to `condition` at runtime.  Used
-    /// internally for [`Boundary::from_delim`] method.
-    pub arg: Option<&'static str>,
+    pub name: Cow<'static, str>,
+    /// Determines if this boundary is present at the start of the string.
+    pub condition: Condition,
     /// Where the beginning of the boundary is.
     pub start: usize,
     /// The length of the boundary.  This is the number of graphemes that
     /// are removed when splitting.
+    ///
+    /// For a [`Condition::Delim`] the delimiter is matched and removed on its own, so
+    /// this is only approximate.  [`from_delim`](Boundary::from_delim) is `const` and
+    /// counts the characters of the delimiter, while
+    /// [`from_owned_delim`](Boundary::from_owned_delim) counts its graphemes.
     pub len: usize,
 }
 
+/// Determines if a boundary is present at the start of a list of graphemes.
+///
+/// This is implemented for any closure `Fn(&[&str]) -> bool`, so a boundary can be built
+/// from runtime data by capturing it.  Use [`Boundary::from_condition`] to create a boundary
+/// from a condition.
+///
+/// A condition is given the graphemes from the position being checked up to the end
+/// of a window of [`LOOKAHEAD`] graphemes, widened to fit the longest delimiter of the
+/// boundaries being split on.  On longer identifiers it does not see the rest of the
+/// identifier, so a condition that looks at where the identifier ends, such as "this is
+/// the last word", only holds when the end is within that window.
+/// ```
+/// use convert_case::{Boundary, BoundaryCondition};
+///
+/// // Splits on any of a set of delimiters loaded at runtime
+/// struct OneOf(Vec<String>);
+///
+/// impl BoundaryCondition for OneOf {
+///     fn is_boundary(&self, graphemes: &[&str]) -> bool {
+///         graphemes
+///             .first()
+///             .map(|g| self.0.iter().any(|d| d == g))
+///             .unwrap_or(false)
+///     }
+/// }
+///
+/// let delims = OneOf(vec![String::from("."), String::from("/")]);
+/// let boundary = Boundary::from_condition("DotOrSlash", delims, 0, 1);
+/// assert_eq!(
+///     vec!["src", "lib", "rs"],
+///     convert_case::split(&"src/lib.rs", &[boundary]),
+/// );
+/// ```
+pub trait BoundaryCondition: Send + Sync {
+    /// Whether the boundary is present at the start of `graphemes`.
+    fn is_boundary(&self, graphemes: &[&str]) -> bool;
+}
+
+impl<F> BoundaryCondition for F
+where
+    F: Fn(&[&str]) -> bool + Send + Sync,
+{
+    fn is_boundary(&self, graphemes: &[&str]) -> bool {
+        self(graphemes)
+    }
+}
+
+/// How a [`Boundary`] determines if it is present at the start of a list of graphemes.
+#[derive(Clone)]
+pub enum Condition {
+    /// A function, as used by the boundary constants.  A closure that captures
+    /// nothing can be used here.  It sees the same window of graphemes as a
+    /// [`BoundaryCondition`].
+    Fn(fn(&[&str]) -> bool),
+    /// Present when the graphemes start with the graphemes of the delimiter.  Used by
+    /// [`Boundary::from_delim`] and [`Boundary::from_owned_delim`].
+    Delim(Cow<'static, str>),
+    /// Any [`BoundaryCondition`], which may hold runtime data.  Used by
+    /// [`Boundary::from_condition`].
+    #[cfg(target_has_atomic = "ptr")]
+    Dyn(Arc<dyn BoundaryCondition>),
+}
+
+impl Condition {
+    fn is_boundary(&self, graphemes: &[&str]) -> bool {
+        match self {
+            Condition::Fn(f) => f(graphemes),
+            Condition::Delim(delim) => delim_len(delim, graphemes).is_some(),
+            #[cfg(target_has_atomic = "ptr")]
+            Condition::Dyn(condition) => condition.is_boundary(graphemes),
+        }
+    }
+}
+
+/// The number of graphemes of `delim` if `graphemes` starts with them.  An empty
+/// delimiter is never present.
+fn delim_len(delim: &str, graphemes: &[&str]) -> Option<usize> {
+    let mut len = 0;
+    for d in delim.graphemes(true) {
+        if graphemes.get(len) != Some(&d) {
+            return None;
+        }
+        len += 1;
+    }
+    (len > 0).then_some(len)
+}
+
+/// The number of chars in `s`, which is the number of graphemes for most delimiters.
+const fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Count every byte that is not a UTF-8 continuation byte
+        if bytes[i] & 0xC0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+impl fmt::Debug for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Condition::Fn(_) => f.write_str("Fn(..)"),
+            Condition::Delim(delim) => f.debug_tuple("Delim").field(delim).finish(),
+            #[cfg(target_has_atomic = "ptr")]
+            Condition::Dyn(_) => f.write_str("Dyn(..)"),
+        }
+    }
+}
+
 impl PartialEq for Boundary {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
     }
 }
 
+impl Eq for Boundary {}
+
+impl Hash for Boundary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
 impl Boundary {
     /// Splits on `_`, consuming the character on segmentation.
     /// ```
@@ -74,9 +278,8 @@ impl Boundary {
     /// );
     /// ```
     pub const UNDERSCORE: Boundary = Boundary {
-        name: "Underscore",
-        condition: |s, _| s.get(0) == Some(&"_"),
-        arg: None,
+        name: Cow::Borrowed("Underscore"),
+        condition: Condition::Fn(is_underscore),
         start: 0,
         len: 1,
     };
@@ -90,9 +293,8 @@ impl Boundary {
     /// );
     /// ```
     pub const HYPHEN: Boundary = Boundary {
-        name: "Hyphen",
-        condition: |s, _| s.get(0) == Some(&"-"),
-        arg: None,
+        name: Cow::Borrowed("Hyphen"),
+        condition: Condition::Fn(is_hyphen),
         start: 0,
         len: 1,
     };
@@ -106,28 +308,29 @@ impl Boundary {
     /// );
     /// ```
     pub const SPACE: Boundary = Boundary {
-        name: "Space",
-        condition: |s, _| s.get(0) == Some(&" "),
-        arg: None,
+        name: Cow::Borrowed("Space"),
+        condition: Condition::Fn(is_space),
         start: 0,
         len: 1,
     };
 
-    /// Splits where a lowercase letter is followed by an uppercase letter.
+    /// Splits where a lowercase letter is followed by an uppercase letter.  A titlecase
+    /// letter like `ǅ` ends with a lowercase letter and starts with an uppercase one, so
+    /// it is treated as either.
     /// ```
     /// # use convert_case::Boundary;
     /// assert_eq!(
     ///     vec![Boundary::LOWER_UPPER],
     ///     Boundary::defaults_from("aA")
     /// );
+    /// assert_eq!(
+    ///     vec![Boundary::LOWER_UPPER],
+    ///     Boundary::defaults_from("aǅ")
+    /// );
     /// ```
     pub const LOWER_UPPER: Boundary = Boundary {
-        name: "LowerUpper",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_lowercase) == Some(true)
-                && s.get(1).map(grapheme_is_uppercase) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("LowerUpper"),
+        condition: Condition::Fn(is_lower_upper),
         start: 1,
         len: 0,
     };
@@ -140,12 +343,8 @@ impl Boundary {
     /// );
     /// ```
     pub const UPPER_LOWER: Boundary = Boundary {
-        name: "UpperLower",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_uppercase) == Some(true)
-                && s.get(1).map(grapheme_is_lowercase) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("UpperLower"),
+        condition: Condition::Fn(is_upper_lower),
         start: 1,
         len: 0,
     };
@@ -153,21 +352,21 @@ impl Boundary {
     /// Acronyms are identified by two uppercase letters followed by a lowercase letter.
     /// The word boundary is between the two uppercase letters.  For example, "HTTPRequest"
     /// would have an acronym boundary identified at "PRe" and split into "HTTP" and "Request".
+    /// An uppercase letter followed by a titlecase letter, like `Aǅ`, is also an acronym boundary.
     /// ```
     /// # use convert_case::Boundary;
     /// assert_eq!(
     ///     vec![Boundary::ACRONYM],
     ///     Boundary::defaults_from("AAa")
     /// );
+    /// assert_eq!(
+    ///     vec![Boundary::ACRONYM],
+    ///     Boundary::defaults_from("Aǅ")
+    /// );
     /// ```
     pub const ACRONYM: Boundary = Boundary {
-        name: "Acronym",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_uppercase) == Some(true)
-                && s.get(1).map(grapheme_is_uppercase) == Some(true)
-                && s.get(2).map(grapheme_is_lowercase) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("Acronym"),
+        condition: Condition::Fn(is_acronym),
         start: 1,
         len: 0,
     };
@@ -181,12 +380,8 @@ impl Boundary {
     /// );
     /// ```
     pub const LOWER_DIGIT: Boundary = Boundary {
-        name: "LowerDigit",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_lowercase) == Some(true)
-                && s.get(1).map(grapheme_is_digit) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("LowerDigit"),
+        condition: Condition::Fn(is_lower_digit),
         start: 1,
         len: 0,
     };
@@ -200,12 +395,8 @@ impl Boundary {
     /// );
     /// ```
     pub const UPPER_DIGIT: Boundary = Boundary {
-        name: "UpperDigit",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_uppercase) == Some(true)
-                && s.get(1).map(grapheme_is_digit) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("UpperDigit"),
+        condition: Condition::Fn(is_upper_digit),
         start: 1,
         len: 0,
     };
@@ -219,12 +410,8 @@ impl Boundary {
     /// );
     /// ```
     pub const DIGIT_LOWER: Boundary = Boundary {
-        name: "DigitLower",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_digit) == Some(true)
-                && s.get(1).map(grapheme_is_lowercase) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("DigitLower"),
+        condition: Condition::Fn(is_digit_lower),
         start: 1,
         len: 0,
     };
@@ -238,12 +425,8 @@ impl Boundary {
     /// );
     /// ```
     pub const DIGIT_UPPER: Boundary = Boundary {
-        name: "DigitUpper",
-        condition: |s, _| {
-            s.get(0).map(grapheme_is_digit) == Some(true)
-                && s.get(1).map(grapheme_is_uppercase) == Some(true)
-        },
-        arg: None,
+        name: Cow::Borrowed("DigitUpper"),
+        condition: Condition::Fn(is_digit_upper),
         start: 1,
         len: 0,
     };
@@ -259,13 +442,109 @@ impl Boundary {
     ///     conv.convert("my::var::name")
     /// )
     /// ```
+    ///
+    /// The delimiter is compared grapheme by grapheme, so multi-byte delimiters
+    /// such as `"→"` split correctly.
+    /// ```
+    /// # use convert_case::{Boundary, split};
+    /// assert_eq!(
+    ///     vec!["from", "to"],
+    ///     split(&"from→to", &[Boundary::from_delim("→")])
+    /// )
+    /// ```
     pub const fn from_delim(delim: &'static str) -> Boundary {
         Boundary {
-            name: delim,
-            arg: Some(delim),
-            condition: |s, arg| s.join("").starts_with(arg.unwrap()),
+            name: Cow::Borrowed(delim),
+            condition: Condition::Delim(Cow::Borrowed(delim)),
+            start: 0,
+            len: char_count(delim),
+        }
+    }
+
+    /// Create a new boundary based on a delimiter that is known only at runtime,
+    /// such as one read from a configuration file.  Otherwise this is the same
+    /// as [`from_delim`](Boundary::from_delim).
+    /// ```
+    /// # use convert_case::{Case, Converter, Boundary};
+    /// let delim = String::from("·");
+    /// let conv = Converter::new()
+    ///     .set_boundaries(&[Boundary::from_owned_delim(delim)])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("middle_dot_case", conv.convert("middle·dot·case"));
+    /// ```
+    pub fn from_owned_delim<T>(delim: T) -> Boundary
+    where
+        T: Into<String>,
+    {
+        let delim = delim.into();
+        let len = delim.graphemes(true).count();
+        Boundary {
+            name: Cow::Owned(delim.clone()),
+            condition: Condition::Delim(Cow::Owned(delim)),
             start: 0,
-            len: delim.len(),
+            len,
+        }
+    }
+
+    /// Create a new boundary from any [`BoundaryCondition`], such as a closure that
+    /// captures runtime data.  The boundary begins `start` graphemes after where
+    /// the condition is met and removes `len` graphemes when splitting.
+    /// ```
+    /// # use convert_case::{Boundary, Case, Converter};
+    /// let separators = vec!['.', '/'];
+    /// let boundary = Boundary::from_condition(
+    ///     "Separator",
+    ///     move |s: &[&str]| {
+    ///         s.first()
+    ///             .map(|g| g.chars().all(|c| separators.contains(&c)))
+    ///             .unwrap_or(false)
+    ///     },
+    ///     0,
+    ///     1,
+    /// );
+    /// let conv = Converter::new()
+    ///     .set_boundaries(&[boundary])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("src_main_rs", conv.convert("src/main.rs"));
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn from_condition<N, C>(name: N, condition: C, start: usize, len: usize) -> Boundary
+    where
+        N: Into<Cow<'static, str>>,
+        C: BoundaryCondition + 'static,
+    {
+        Boundary {
+            name: name.into(),
+            condition: Condition::Dyn(Arc::new(condition)),
+            start,
+            len,
+        }
+    }
+
+    /// Whether this boundary is present at the start of `graphemes`.
+    /// ```
+    /// # use convert_case::Boundary;
+    /// assert!(Boundary::LOWER_UPPER.is_boundary(&["a", "B"]));
+    /// assert!(!Boundary::LOWER_UPPER.is_boundary(&["A", "b"]));
+    /// ```
+    pub fn is_boundary(&self, graphemes: &[&str]) -> bool {
+        self.condition.is_boundary(graphemes)
+    }
+
+    /// The number of graphemes removed if this boundary is present at the start
+    /// of `graphemes`.  Delimiter boundaries remove exactly the graphemes of the delimiter.
+    fn matched_len(&self, graphemes: &[&str]) -> Option<usize> {
+        match &self.condition {
+            Condition::Delim(delim) => delim_len(delim, graphemes),
+            _ => self.is_boundary(graphemes).then_some(self.len),
+        }
+    }
+
+    /// The number of graphemes this boundary needs to see to match.
+    fn lookahead(&self) -> usize {
+        match &self.condition {
+            Condition::Delim(delim) => delim.graphemes(true).count(),
+            _ => self.start + self.len,
         }
     }
 
@@ -302,6 +581,21 @@ impl Boundary {
         ]
     }
 
+    /// Every boundary constant defined on `Boundary`.
+    #[cfg(feature = "serde")]
+    pub(crate) const BUILTINS: [Boundary; 10] = [
+        Boundary::UNDERSCORE,
+        Boundary::HYPHEN,
+        Boundary::SPACE,
+        Boundary::LOWER_UPPER,
+        Boundary::UPPER_LOWER,
+        Boundary::ACRONYM,
+        Boundary::LOWER_DIGIT,
+        Boundary::UPPER_DIGIT,
+        Boundary::DIGIT_LOWER,
+        Boundary::DIGIT_UPPER,
+    ];
+
     /// Returns the boundaries that involve digits.
     /// ```
     /// # use convert_case::Boundary;
@@ -385,8 +679,8 @@ impl Boundary {
     pub fn defaults_from(pattern: &str) -> Vec<Boundary> {
         let mut boundaries = Vec::new();
         for boundary in Boundary::defaults() {
-            let parts = split(&pattern, &[boundary]);
-            if parts.len() > 1 || parts.len() == 0 || parts[0] != pattern {
+            let parts = split(&pattern, core::slice::from_ref(&boundary));
+            if parts.len() > 1 || parts.is_empty() || parts[0] != pattern {
                 boundaries.push(boundary);
             }
         }
@@ -397,7 +691,8 @@ impl Boundary {
 /// Split an identifier into a list of words using the list of boundaries.
 ///
 /// This is used internally for splitting an identifier before mutating by
-/// a pattern and joining again with a delimiter.
+/// a pattern and joining again with a delimiter.  It collects the words of
+/// [`split_iter`] into a vector.
 /// ```
 /// use convert_case::{Boundary, split};
 /// assert_eq!(
@@ -408,43 +703,426 @@ impl Boundary {
 pub fn split<'s, T>(s: &'s T, boundaries: &[Boundary]) -> Vec<&'s str>
 where
     T: AsRef<str>,
+{
+    split_iter(s, boundaries).collect()
+}
+
+/// Lazily split an identifier into words using the list of boundaries.
+///
+/// Words are yielded as they are found, without collecting the graphemes of the
+/// identifier or the words into vectors.  Boundary conditions are checked against a
+/// window of [`LOOKAHEAD`] graphemes, which is widened to fit the longest delimiter,
+/// or `start + len`, of the boundaries.  A condition never sees more of the identifier
+/// than that.
+/// ```
+/// use convert_case::{Boundary, split_iter};
+/// let mut words = split_iter(&"one_two-three", &[Boundary::UNDERSCORE, Boundary::HYPHEN]);
+/// assert_eq!(Some("one"), words.next());
+/// assert_eq!(Some("two"), words.next());
+/// assert_eq!(Some("three"), words.next());
+/// assert_eq!(None, words.next());
+/// ```
+pub fn split_iter<'s, 'b, T>(s: &'s T, boundaries: &'b [Boundary]) -> SplitIter<'s, 'b>
+where
+    T: AsRef<str> + ?Sized,
+{
+    SplitIter {
+        spans: split_spans(s, boundaries),
+    }
+}
+
+/// Split an identifier into words along with where each word came from.
+///
+/// Each [`Span`] holds the byte range of the word in the identifier, the boundary
+/// that ended it, and the delimiter that boundary consumed.  The words are the same
+/// as those of [`split_iter`], and are found just as lazily.
+/// ```
+/// use convert_case::{Boundary, split_spans};
+/// let spans: Vec<_> = split_spans(&"one_two", &[Boundary::UNDERSCORE]).collect();
+///
+/// assert_eq!("one", spans[0].word);
+/// assert_eq!(0..3, spans[0].range);
+/// assert_eq!(Some(&Boundary::UNDERSCORE), spans[0].boundary);
+/// assert_eq!("_", spans[0].delim);
+///
+/// assert_eq!("two", spans[1].word);
+/// assert_eq!(4..7, spans[1].range);
+/// assert_eq!(None, spans[1].boundary);
+/// ```
+pub fn split_spans<'s, 'b, T>(s: &'s T, boundaries: &'b [Boundary]) -> SplitSpans<'s, 'b>
+where
+    T: AsRef<str> + ?Sized,
 {
     let s = s.as_ref();
+    let lookahead = boundaries
+        .iter()
+        .map(Boundary::lookahead)
+        .fold(LOOKAHEAD, usize::max);
+    SplitSpans {
+        s,
+        boundaries,
+        graphemes: s.grapheme_indices(true),
+        exhausted: false,
+        lookahead,
+        window: Buffer::new("", 2 * lookahead),
+        offsets: Buffer::new(0, 2 * lookahead),
+        head: 0,
+        tail: 0,
+        word_start: 0,
+        lexicon: Words::Borrowed(&[]),
+        protected_until: 0,
+        skip: 0,
+        done: false,
+    }
+}
+
+/// The number of graphemes a boundary condition is given by [`split_iter`], unless a
+/// boundary needs more to match its delimiter.
+pub const LOOKAHEAD: usize = 32;
+
+/// A buffer kept inline when it is twice [`LOOKAHEAD`] or shorter, so splitting only
+/// allocates for boundaries that look further ahead.
+#[derive(Debug, Clone)]
+enum Buffer<T> {
+    Inline([T; 2 * LOOKAHEAD]),
+    Heap(Vec<T>),
+}
 
-    if s.len() == 0 {
-        return Vec::new();
+impl<T: Copy> Buffer<T> {
+    fn new(value: T, len: usize) -> Self {
+        if len <= 2 * LOOKAHEAD {
+            Buffer::Inline([value; 2 * LOOKAHEAD])
+        } else {
+            Buffer::Heap(alloc::vec![value; len])
+        }
     }
+}
 
-    let mut words = Vec::new();
-    let mut last_boundary_end = 0;
+impl<T> Deref for Buffer<T> {
+    type Target = [T];
 
-    let (indices, graphemes): (Vec<_>, Vec<_>) = s.grapheme_indices(true).unzip();
-    let grapheme_length = indices[graphemes.len() - 1] + graphemes[graphemes.len() - 1].len();
+    fn deref(&self) -> &[T] {
+        match self {
+            Buffer::Inline(buf) => buf,
+            Buffer::Heap(buf) => buf,
+        }
+    }
+}
+
+impl<T> DerefMut for Buffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        match self {
+            Buffer::Inline(buf) => buf,
+            Buffer::Heap(buf) => buf,
+        }
+    }
+}
+
+/// Common mixed-case words that can be kept whole when splitting with a
+/// [`Converter`](crate::Converter).
+///
+/// Boundaries like [`LOWER_UPPER`](Boundary::LOWER_UPPER) and [`ACRONYM`](Boundary::ACRONYM)
+/// would otherwise split these into pieces, such as `"Graph"` and `"QL"`.  The lexicon
+/// is empty by default, so this list has to be added to a converter.
+/// ```
+/// use convert_case::{Case, Casing, DEFAULT_LEXICON};
+/// assert_eq!("graph_ql_schema", "GraphQLSchema".to_case(Case::Snake));
+/// assert_eq!(
+///     "graphql_schema",
+///     "GraphQLSchema".from_case(Case::Pascal).add_lexicon(DEFAULT_LEXICON).to_case(Case::Snake)
+/// );
+/// ```
+pub const DEFAULT_LEXICON: &[&str] = &[
+    "GraphQL",
+    "OAuth",
+    "OpenAPI",
+    "OpenID",
+    "iOS",
+    "iPadOS",
+    "macOS",
+    "tvOS",
+    "watchOS",
+    "iPad",
+    "iPhone",
+    "IPv4",
+    "IPv6",
+    "JavaScript",
+    "TypeScript",
+    "GitHub",
+    "GitLab",
+    "MySQL",
+    "NoSQL",
+    "PostgreSQL",
+    "SQLite",
+    "WiFi",
+    "YouTube",
+    "LaTeX",
+];
+
+/// A list of words, such as a lexicon, that is either owned by a [`Converter`](crate::Converter)
+/// or borrowed by a [`BorrowedConverter`](crate::BorrowedConverter).
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Words<'a> {
+    Owned(&'a [Cow<'static, str>]),
+    Borrowed(&'a [&'a str]),
+}
+
+impl<'a> Words<'a> {
+    pub(crate) fn iter(self) -> impl Iterator<Item = &'a str> {
+        let (owned, borrowed): (&[Cow<str>], &[&str]) = match self {
+            Words::Owned(words) => (words, &[]),
+            Words::Borrowed(words) => (&[], words),
+        };
+        owned.iter().map(|w| &**w).chain(borrowed.iter().copied())
+    }
+}
+
+/// A word of an identifier and where it came from, created by [`split_spans`].
+///
+/// Spans can explain how an identifier was split during a conversion.
+/// ```
+/// use convert_case::{Case, Casing};
+///
+/// let trace: Vec<String> = Case::Camel
+///     .split_spans(&"myJSONParser")
+///     .map(|span| match span.boundary {
+///         Some(boundary) => format!("{:?} {:?} ended by {}", span.word, span.range, boundary.name),
+///         None => format!("{:?} {:?}", span.word, span.range),
+///     })
+///     .collect();
+///
+/// assert_eq!(
+///     vec![
+///         "\"my\" 0..2 ended by LowerUpper",
+///         "\"JSON\" 2..6 ended by Acronym",
+///         "\"Parser\" 6..12",
+///     ],
+///     trace,
+/// );
+/// assert_eq!("my_json_parser", "myJSONParser".from_case(Case::Camel).to_case(Case::Snake));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span<'s, 'b> {
+    /// The word, which is the slice of the identifier at `range`.
+    pub word: &'s str,
+    /// The byte range of the word in the identifier.
+    pub range: Range<usize>,
+    /// The boundary that ended the word, or `None` for the last word.
+    pub boundary: Option<&'b Boundary>,
+    /// The graphemes removed by `boundary` after the word, which is empty
+    /// for boundaries that do not consume any graphemes.
+    pub delim: &'s str,
+}
+
+/// An iterator over the words of an identifier, created by [`split_iter`]
+/// or [`Case::split_iter`](crate::Case::split_iter).
+#[derive(Debug, Clone)]
+pub struct SplitIter<'s, 'b> {
+    spans: SplitSpans<'s, 'b>,
+}
+
+impl<'b> SplitIter<'_, 'b> {
+    /// Keep the words of `lexicon` whole.  See [`SplitSpans::with_lexicon`].
+    pub fn with_lexicon(self, lexicon: &'b [Cow<'static, str>]) -> Self {
+        self.with_words(Words::Owned(lexicon))
+    }
+
+    pub(crate) fn with_words(self, lexicon: Words<'b>) -> Self {
+        SplitIter {
+            spans: self.spans.with_words(lexicon),
+        }
+    }
+}
+
+impl<'s> Iterator for SplitIter<'s, '_> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<&'s str> {
+        self.spans.next().map(|span| span.word)
+    }
+}
+
+/// An iterator over the spans of an identifier, created by [`split_spans`]
+/// or [`Case::split_spans`](crate::Case::split_spans).
+#[derive(Debug, Clone)]
+pub struct SplitSpans<'s, 'b> {
+    s: &'s str,
+    boundaries: &'b [Boundary],
+    graphemes: GraphemeIndices<'s>,
+    exhausted: bool,
+    lookahead: usize,
+    // The graphemes from the current position onward are `window[head..tail]`, beginning
+    // at the byte offsets in `offsets[head..tail]`.  Twice the lookahead is buffered so
+    // the window only needs to be shifted back to the front every `lookahead` graphemes.
+    window: Buffer<&'s str>,
+    offsets: Buffer<usize>,
+    head: usize,
+    tail: usize,
+    word_start: usize,
+    lexicon: Words<'b>,
+    // Splits between `word_start` and this byte offset would break up a word of the lexicon
+    protected_until: usize,
+    // Graphemes removed by a boundary are not checked for further boundaries
+    skip: usize,
+    done: bool,
+}
+
+impl<'b> SplitSpans<'_, 'b> {
+    /// Keep the words of `lexicon` whole.  When a word begins with one of them and a boundary
+    /// or the end of the identifier follows it, no boundary may split it until the end of the
+    /// lexicon word.  Lexicon words never introduce splits of their own, so they only have an
+    /// effect when the boundaries would split them up.
+    /// ```
+    /// use std::borrow::Cow;
+    /// use convert_case::{Case, split_iter};
+    ///
+    /// let lexicon = [Cow::Borrowed("SQLite")];
+    /// let boundaries = Case::Camel.boundaries();
+    /// assert_eq!(
+    ///     vec!["open", "SQ", "Lite", "Db"],
+    ///     split_iter("openSQLiteDb", boundaries).collect::<Vec<_>>(),
+    /// );
+    /// assert_eq!(
+    ///     vec!["open", "SQLite", "Db"],
+    ///     split_iter("openSQLiteDb", boundaries).with_lexicon(&lexicon).collect::<Vec<_>>(),
+    /// );
+    /// ```
+    pub fn with_lexicon(self, lexicon: &'b [Cow<'static, str>]) -> Self {
+        self.with_words(Words::Owned(lexicon))
+    }
+
+    pub(crate) fn with_words(mut self, lexicon: Words<'b>) -> Self {
+        self.lexicon = lexicon;
+        self.protect();
+        self
+    }
+
+    /// Protects the longest lexicon word at the start of the current word.
+    fn protect(&mut self) {
+        let rest = &self.s[self.word_start..];
+        self.protected_until = self
+            .lexicon
+            .iter()
+            .filter(|word| !word.is_empty() && rest.starts_with(word))
+            .map(|word| self.word_start + word.len())
+            .filter(|&end| self.ends_word(end))
+            .max()
+            .unwrap_or(0);
+    }
+
+    /// Whether a lexicon word ending at the byte offset `end` is followed by the end of
+    /// the identifier or a boundary, so that `"OAuth"` is not kept whole in `"OAuthority"`.
+    fn ends_word(&self, end: usize) -> bool {
+        if end == self.s.len() {
+            return true;
+        }
+        let word = &self.s[self.word_start..end];
+        self.boundaries.iter().any(|b| {
+            // A boundary is checked `start` graphemes before where it splits
+            let from = match b.start {
+                0 => end,
+                n => match word.grapheme_indices(true).nth_back(n - 1) {
+                    Some((offset, _)) => self.word_start + offset,
+                    None => return false,
+                },
+            };
+            let mut window = Buffer::new("", self.lookahead);
+            let mut len = 0;
+            for grapheme in self.s[from..].graphemes(true).take(self.lookahead) {
+                window[len] = grapheme;
+                len += 1;
+            }
+            b.matched_len(&window[..len]).is_some()
+        })
+    }
+
+    fn is_protected(&self, split: usize) -> bool {
+        self.word_start < split && split < self.protected_until
+    }
+
+    fn fill(&mut self) {
+        if self.tail == self.window.len() {
+            self.window.copy_within(self.head..self.tail, 0);
+            self.offsets.copy_within(self.head..self.tail, 0);
+            self.tail -= self.head;
+            self.head = 0;
+        }
+        while !self.exhausted && self.tail - self.head < self.lookahead {
+            match self.graphemes.next() {
+                Some((offset, grapheme)) => {
+                    self.window[self.tail] = grapheme;
+                    self.offsets[self.tail] = offset;
+                    self.tail += 1;
+                }
+                None => self.exhausted = true,
+            }
+        }
+    }
+
+    /// The byte offset of the grapheme `n` graphemes after the current position.
+    fn offset(&self, n: usize) -> usize {
+        let buffered = self.tail - self.head;
+        if n < buffered {
+            self.offsets[self.head + n]
+        } else {
+            self.graphemes
+                .clone()
+                .nth(n - buffered)
+                .map_or(self.s.len(), |(offset, _)| offset)
+        }
+    }
+}
+
+impl<'s, 'b> Iterator for SplitSpans<'s, 'b> {
+    type Item = Span<'s, 'b>;
 
-    for i in 0..graphemes.len() {
-        for boundary in boundaries {
-            //let byte_index = indices[i];
+    fn next(&mut self) -> Option<Span<'s, 'b>> {
+        while !self.done {
+            self.fill();
+            if self.head == self.tail {
+                self.done = true;
+                let range = self.word_start..self.s.len();
+                return (!range.is_empty()).then(|| Span {
+                    word: &self.s[range.clone()],
+                    range,
+                    boundary: None,
+                    delim: "",
+                });
+            }
 
-            if (boundary.condition)(&graphemes[i..], boundary.arg) {
-                // What if we find a condition at the end of the array?
-                // Maybe we can stop early based on length
-                // To do this, need to switch the loops
-                // TODO
-                let boundary_byte_start: usize =
-                    *indices.get(i + boundary.start).unwrap_or(&grapheme_length);
-                let boundary_byte_end: usize = *indices
-                    .get(i + boundary.start + boundary.len)
-                    .unwrap_or(&grapheme_length);
+            let window = &self.window[self.head..self.tail];
+            let found = if self.skip > 0 {
+                self.skip -= 1;
+                None
+            } else {
+                let boundaries = self.boundaries;
+                boundaries.iter().find_map(|b| {
+                    let len = b.matched_len(window)?;
+                    (!self.is_protected(self.offset(b.start))).then_some((b, len))
+                })
+            };
+            let span = found.map(|(boundary, len)| {
+                let delim_start = self.offset(boundary.start);
+                let delim_end = self.offset(boundary.start + len);
+                let range = self.word_start..delim_start;
+                self.word_start = delim_end;
+                self.protect();
+                self.skip = (boundary.start + len).saturating_sub(1);
+                Span {
+                    word: &self.s[range.clone()],
+                    range,
+                    boundary: Some(boundary),
+                    delim: &self.s[delim_start..delim_end],
+                }
+            });
+            self.head += 1;
 
-                // todo clean this up a bit
-                words.push(&s[last_boundary_end..boundary_byte_start]);
-                last_boundary_end = boundary_byte_end;
-                break;
+            if let Some(span) = span.filter(|span| !span.word.is_empty()) {
+                return Some(span);
             }
         }
+        None
     }
-    words.push(&s[last_boundary_end..]);
-    words.into_iter().filter(|s| !s.is_empty()).collect()
 }
 
 // ascii version
@@ -556,6 +1234,157 @@ mod tests {
         assert_eq!(vec!["lower", "Upper", "Upper"], v)
     }
 
+    #[test]
+    fn from_condition_captures() {
+        let words = ["graphql".to_string(), "schema".to_string()];
+        let boundary = Boundary::from_condition(
+            "BeforeWord",
+            move |s: &[&str]| words.iter().any(|w| s.concat().starts_with(w.as_str())),
+            0,
+            0,
+        );
+        let v = split(&"usegraphqlschema", &[boundary]);
+        assert_eq!(vec!["use", "graphql", "schema"], v);
+    }
+
+    #[test]
+    fn condition_fn_and_dyn_equal_by_name() {
+        let dyn_underscore =
+            Boundary::from_condition("Underscore", |s: &[&str]| s.first() == Some(&"_"), 0, 1);
+        assert_eq!(Boundary::UNDERSCORE, dyn_underscore);
+    }
+
+    #[test]
+    fn from_delim_multibyte() {
+        for delim in ["→", "·", "é"] {
+            let s = ["one", "two", "three"].join(delim);
+            let v = split(&s, &[Boundary::from_owned_delim(delim)]);
+            assert_eq!(vec!["one", "two", "three"], v);
+        }
+    }
+
+    #[test]
+    fn from_delim_combining_grapheme() {
+        // "e" followed by a combining acute accent is a single grapheme
+        let delim = "e\u{301}";
+        let s = format!("one{}two", delim);
+        let b = Boundary::from_owned_delim(delim);
+        assert_eq!(1, b.len);
+        assert_eq!(vec!["one", "two"], split(&s, &[b]));
+
+        // Counted in characters, but still matched as a single grapheme
+        let b = Boundary::from_delim("e\u{301}");
+        assert_eq!(2, b.len);
+        assert_eq!(vec!["one", "two"], split(&s, &[b]));
+    }
+
+    #[test]
+    fn overlapping_delims() {
+        let v = split(
+            &"a::b:c",
+            &[Boundary::from_delim("::"), Boundary::from_delim(":")],
+        );
+        assert_eq!(vec!["a", "b", "c"], v);
+    }
+
+    #[test]
+    fn owned_delim_equals_static() {
+        assert_eq!(
+            Boundary::from_delim("::"),
+            Boundary::from_owned_delim(String::from("::"))
+        );
+    }
+
+    #[test]
+    fn split_iter_longer_than_window() {
+        let words = ["alpha", "beta", "gamma", "delta"].repeat(LOOKAHEAD);
+        let s = words.join("_");
+        let split: Vec<_> = split_iter(&s, &[Boundary::UNDERSCORE]).collect();
+        assert_eq!(words, split);
+    }
+
+    #[test]
+    fn split_iter_resumes() {
+        let mut words = split_iter("a-b__c-", &[Boundary::UNDERSCORE, Boundary::HYPHEN]);
+        assert_eq!(Some("a"), words.next());
+        assert_eq!(Some("b"), words.next());
+        assert_eq!(Some("c"), words.next());
+        assert_eq!(None, words.next());
+        assert_eq!(None, words.next());
+    }
+
+    #[test]
+    fn spans_cover_identifier() {
+        let s = "ὀδυσσεύς::Σαλαμίνα__Δ";
+        let b = [Boundary::from_delim("::"), Boundary::UNDERSCORE];
+        let spans: Vec<_> = split_spans(s, &b).collect();
+
+        assert_eq!(3, spans.len());
+        for span in &spans {
+            assert_eq!(span.word, &s[span.range.clone()]);
+        }
+        assert_eq!("::", spans[0].delim);
+        assert_eq!(Some(&b[0]), spans[0].boundary);
+        // The second underscore ends an empty word, which is skipped
+        assert_eq!("_", spans[1].delim);
+        assert_eq!(Some(&b[1]), spans[1].boundary);
+        assert_eq!(s.len(), spans[2].range.end);
+        assert_eq!(None, spans[2].boundary);
+    }
+
+    #[test]
+    fn titlecase_letters_start_words() {
+        let b = Boundary::defaults();
+        assert_eq!(vec!["ǆungla", "ǅungla"], split(&"ǆunglaǅungla", &b));
+        assert_eq!(vec!["ǅ", "Bar"], split(&"ǅBar", &b));
+        assert_eq!(vec!["XML", "ǅungla"], split(&"XMLǅungla", &b));
+        assert_eq!(vec!["ǅungla"], split(&"ǅungla", &b));
+    }
+
+    #[test]
+    fn conditions_see_lookahead_window() {
+        let boundary = Boundary {
+            name: "Window".into(),
+            condition: Condition::Fn(|s| s.len() == LOOKAHEAD && s[LOOKAHEAD - 1] == "!"),
+            start: LOOKAHEAD - 1,
+            len: 1,
+        };
+        let s = format!("{}!{}", "a".repeat(LOOKAHEAD - 1), "b".repeat(LOOKAHEAD));
+        assert_eq!(
+            vec!["a".repeat(LOOKAHEAD - 1), "b".repeat(LOOKAHEAD)],
+            split(&s, &[boundary])
+        );
+    }
+
+    #[test]
+    fn delimiters_longer_than_lookahead() {
+        let delim = "-".repeat(LOOKAHEAD + 8);
+        let s = format!("left{}right{}", delim, "-".repeat(LOOKAHEAD));
+        let boundary = Boundary::from_owned_delim(delim);
+        assert_eq!(
+            vec![
+                "left".to_string(),
+                format!("right{}", "-".repeat(LOOKAHEAD))
+            ],
+            split(&s, &[boundary])
+        );
+    }
+
+    #[test]
+    fn window_fits_boundary_start() {
+        let boundary = Boundary {
+            name: "Far".into(),
+            condition: Condition::Fn(|s| s.len() > 40 && s[40] == "!"),
+            start: 40,
+            len: 1,
+        };
+        let s = format!("{}!b", "a".repeat(40));
+        assert_eq!(
+            vec!["a".repeat(40), "b".to_string()],
+            split(&s, &[boundary])
+        );
+    }
+
     #[test]
     fn from_delim_double_colon() {
         let boundary = Boundary::from_delim("::");
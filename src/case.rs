@@ -1,8 +1,14 @@
-use crate::boundary::{self, Boundary};
-use crate::pattern;
+use crate::boundary::{self, Boundary, SplitIter, SplitSpans};
+use crate::detect::{self, Detection};
+use crate::pattern::{self, Pattern};
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
+use core::str::FromStr;
 
 /// Defines the case of an identifier.
 ///
@@ -27,6 +33,9 @@ use alloc::vec::Vec;
 ///
 /// There are other less common cases, such as [`Case::Sentence`], [`Case::Alternating`], and [`Case::Toggle`].
 ///
+/// Cases for qualified names are delimited by periods, as in [`Case::Dot`] and [`Case::UpperDot`],
+/// by slashes, as in [`Case::Path`], or by double colons, as in [`Case::Namespace`].
+///
 /// Then there are two random cases [`Case::Random`] and [`Case::PseudoRandom`] from the `random` feature.
 ///
 /// This crate provides the ability to convert "from" a case.  This introduces a different feature
@@ -35,9 +44,9 @@ use alloc::vec::Vec;
 /// camel case identifier `myVarName` is split where a lowercase letter is followed by an
 /// uppercase letter.  Each case is also associated with a list of boundaries that are used when
 /// converting "from" a particular case.
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub enum Case<'a> {
-    /// Custom cases can be delimited by any static string slice and mutate words
+    /// Custom cases can be delimited by any string slice and mutate words
     /// using any pattern.  Further, they can use any list of boundaries for
     /// splitting identifiers into words.
     ///
@@ -47,7 +56,7 @@ pub enum Case<'a> {
     /// use convert_case::{Case, Casing, Boundary, pattern};
     /// let dot_case = Case::Custom {
     ///     boundaries: &[Boundary::from_delim(".")],
-    ///     pattern: pattern::lowercase,
+    ///     pattern: &pattern::lowercase,
     ///     delim: ".",
     /// };
     ///
@@ -60,10 +69,49 @@ pub enum Case<'a> {
     ///     "my.new.case".from_case(dot_case).to_case(Case::Title),
     /// );
     /// ```
+    ///
+    /// The boundaries, pattern and delimiter are borrowed, so they can also be built at
+    /// runtime, including a pattern from a boxed closure.
+    /// ```
+    /// use convert_case::{Case, Casing, Boundary, pattern::{self, Pattern}};
+    /// let delim = String::from("/");
+    /// let boundaries = [Boundary::from_owned_delim(delim.as_str())];
+    /// let lowercase: Box<dyn Pattern> = Box::new(|words: &[&str]| pattern::lowercase(words));
+    /// let path_case = Case::Custom {
+    ///     boundaries: &boundaries,
+    ///     pattern: &lowercase,
+    ///     delim: &delim,
+    /// };
+    ///
+    /// assert_eq!("my/new/case", "myNewCase".to_case(path_case));
+    /// ```
+    ///
+    /// The pattern can be any [`Pattern`](pattern::Pattern), including one that holds its
+    /// own configuration.
+    /// ```
+    /// use convert_case::{Case, Casing, Boundary, pattern::{self, Pattern}};
+    /// struct Prefixed(&'static str);
+    ///
+    /// impl Pattern for Prefixed {
+    ///     fn mutate(&self, words: &[&str]) -> Vec<String> {
+    ///         let mut words = pattern::lowercase(words);
+    ///         words.insert(0, self.0.to_string());
+    ///         words
+    ///     }
+    /// }
+    ///
+    /// static PREFIXED: Prefixed = Prefixed("x");
+    /// let header_case = Case::Custom {
+    ///     boundaries: &Boundary::defaults(),
+    ///     pattern: &PREFIXED,
+    ///     delim: "-",
+    /// };
+    /// assert_eq!("x-request-id", "RequestId".to_case(header_case));
+    /// ```
     Custom {
         boundaries: &'a [Boundary],
-        pattern: fn(&[&str]) -> Vec<String>,
-        delim: &'static str,
+        pattern: &'a dyn Pattern,
+        delim: &'a str,
     },
 
     /// Snake case strings are delimited by underscores `_` and are all lowercase.
@@ -217,7 +265,8 @@ pub enum Case<'a> {
 
     /// Title case strings are delimited by spaces. Only the leading character of
     /// each word is uppercase.  No inferences are made about language, so words
-    /// like "as", "to", and "for" will still be capitalized.
+    /// like "as", "to", and "for" will still be capitalized.  To follow a style guide
+    /// instead, use the [`TitleCase`](pattern::TitleCase) pattern.
     /// * Boundaries: [Space](`Boundary::SPACE`)
     /// * Pattern: [capital](`pattern::capital`)
     /// * Delimeter: Space `" "`
@@ -264,6 +313,51 @@ pub enum Case<'a> {
     /// ```
     Toggle,
 
+    /// Dot case strings are delimited by periods `.` and are all lowercase.
+    /// * Boundaries: [Period](Boundary::from_delim)
+    /// * Pattern: [lowercase](pattern::lowercase)
+    /// * Delimeter: Period `"."`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("my.variable.name", "My variable NAME".to_case(Case::Dot))
+    /// ```
+    Dot,
+
+    /// Upper dot case strings are delimited by periods `.` and are all uppercase.
+    /// * Boundaries: [Period](Boundary::from_delim)
+    /// * Pattern: [uppercase](pattern::uppercase)
+    /// * Delimeter: Period `"."`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("MY.VARIABLE.NAME", "My variable NAME".to_case(Case::UpperDot))
+    /// ```
+    UpperDot,
+
+    /// Path case strings are delimited by slashes `/` and are all lowercase.
+    /// * Boundaries: [Slash](Boundary::from_delim)
+    /// * Pattern: [lowercase](pattern::lowercase)
+    /// * Delimeter: Slash `"/"`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("my/variable/name", "My variable NAME".to_case(Case::Path))
+    /// ```
+    Path,
+
+    /// Namespace case strings are delimited by double colons `::`.  The leading letter
+    /// of each word is uppercase, while the rest is lowercase.
+    /// * Boundaries: [Double colon](Boundary::from_delim)
+    /// * Pattern: [capital](pattern::capital)
+    /// * Delimeter: Double colon `"::"`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("My::Variable::Name", "My variable NAME".to_case(Case::Namespace))
+    /// ```
+    Namespace,
+
     /// Random case strings are delimited by spaces and characters are
     /// randomly upper case or lower case.  
     ///
@@ -278,7 +372,8 @@ pub enum Case<'a> {
     /// # #[cfg(any(doc, feature = "random"))]
     /// let new = "My variable NAME".to_case(Case::Random);
     /// ```
-    /// String `new` could be "My vaRIAbLE nAme" for example.
+    /// String `new` could be "My vaRIAbLE nAme" for example.  For output that is the same
+    /// every time, seed the generator with [`Converter::set_seed`](crate::Converter::set_seed).
     #[cfg(any(doc, feature = "random"))]
     #[cfg(feature = "random")]
     Random,
@@ -298,13 +393,14 @@ pub enum Case<'a> {
     /// # #[cfg(any(doc, feature = "random"))]
     /// let new = "My variable NAME".to_case(Case::Random);
     /// ```
-    /// String `new` could be "mY vArIAblE NamE" for example.
+    /// String `new` could be "mY vArIAblE NamE" for example.  For output that is the same
+    /// every time, seed the generator with [`Converter::set_seed`](crate::Converter::set_seed).
     #[cfg(any(doc, feature = "random"))]
     #[cfg(feature = "random")]
     PseudoRandom,
 }
 
-impl Case<'_> {
+impl<'a> Case<'a> {
     /// Returns the boundaries used in the corresponding case.  That is, where can word boundaries
     /// be distinguished in a string of the given case.  The table outlines which cases use which
     /// set of boundaries.
@@ -316,7 +412,10 @@ impl Case<'_> {
     /// | Lower, Upper, Title, Alternating, Toggle, Random, PseudoRandom | [SPACE](Boundary::Space) |
     /// | Pascal, UpperCamel, Camel | [LOWER_UPPER](Boundary::LOWER_UPPER), [LOWER_DIGIT](Boundary::LOWER_DIGIT), [UPPER_DIGIT](Boundary::UPPER_DIGIT), [DIGIT_LOWER](Boundary::DIGIT_LOWER), [DIGIT_UPPER](Boundary::DIGIT_UPPER), [ACRONYM](Boundary::ACRONYM) |
     /// | Flat, UpperFlat | No boundaries |
-    pub fn boundaries(&self) -> &[Boundary] {
+    /// | Dot, UpperDot | Period `"."` |
+    /// | Path | Slash `"/"` |
+    /// | Namespace | Double colon `"::"` |
+    pub const fn boundaries(&self) -> &'a [Boundary] {
         use Case::*;
         match self {
             Snake | Constant | UpperSnake | Ada => &[Boundary::UNDERSCORE],
@@ -331,6 +430,9 @@ impl Case<'_> {
                 Boundary::DIGIT_UPPER,
             ],
             UpperFlat | Flat => &[],
+            Dot | UpperDot => DOT,
+            Path => SLASH,
+            Namespace => DOUBLE_COLON,
             Custom { boundaries, .. } => boundaries,
 
             #[cfg(feature = "random")]
@@ -347,13 +449,19 @@ impl Case<'_> {
     /// | Kebab, Cobol, UpperKebab, Train | Hyphen `"-"` |
     /// | Upper, Lower, Title, Sentence, Alternating, Toggle, Random, PseudoRandom | Space `" "` |
     /// | Flat, UpperFlat, Pascal, UpperCamel, Camel | Empty string `""` |
-    pub const fn delim(&self) -> &'static str {
+    /// | Dot, UpperDot | Period `"."` |
+    /// | Path | Slash `"/"` |
+    /// | Namespace | Double colon `"::"` |
+    pub const fn delim(&self) -> &'a str {
         use Case::*;
         match self {
             Snake | Constant | UpperSnake | Ada => "_",
             Kebab | Cobol | UpperKebab | Train => "-",
             Upper | Lower | Title | Sentence | Alternating | Toggle => " ",
             Flat | UpperFlat | Pascal | UpperCamel | Camel => "",
+            Dot | UpperDot => ".",
+            Path => "/",
+            Namespace => "::",
             Custom { delim, .. } => delim,
 
             #[cfg(feature = "random")]
@@ -366,32 +474,145 @@ impl Case<'_> {
     ///
     /// | Cases | Pattern |
     /// | --- | --- |
-    /// | Constant, UpperSnake, Cobol, UpperKebab, UpperFlat, Upper | [uppercase](pattern::uppercase) |
-    /// | Snake, Kebab, Flat, Lower | [lowercase](pattern::lowercase) |
-    /// | Ada, Train, Pascal, UpperCamel, Title | [capital](pattern::capital) |
+    /// | Constant, UpperSnake, Cobol, UpperKebab, UpperFlat, Upper, UpperDot | [uppercase](pattern::uppercase) |
+    /// | Snake, Kebab, Flat, Lower, Dot, Path | [lowercase](pattern::lowercase) |
+    /// | Ada, Train, Pascal, UpperCamel, Title, Namespace | [capital](pattern::capital) |
     /// | Camel | [camel](pattern::camel) |
     /// | Alternating | [alternating](pattern::alternating) |
     /// | Random | [random](pattern::random) |
     /// | PseudoRandom | [pseudo_random](pattern::pseudo_random) |
-    pub const fn pattern(&self) -> pattern::Pattern {
+    pub const fn pattern(&self) -> &'a dyn Pattern {
         use Case::*;
         match self {
-            Constant | UpperSnake | Cobol | UpperKebab | UpperFlat | Upper => pattern::uppercase,
-            Snake | Kebab | Flat | Lower => pattern::lowercase,
-            Ada | Train | Pascal | UpperCamel | Title => pattern::capital,
-            Camel => pattern::camel,
-            Toggle => pattern::toggle,
-            Alternating => pattern::alternating,
-            Sentence => pattern::sentence,
+            Constant | UpperSnake | Cobol | UpperKebab | UpperFlat | Upper | UpperDot => {
+                &pattern::uppercase
+            }
+            Snake | Kebab | Flat | Lower | Dot | Path => &pattern::lowercase,
+            Ada | Train | Pascal | UpperCamel | Title | Namespace => &pattern::capital,
+            Camel => &pattern::camel,
+            Toggle => &pattern::toggle,
+            Alternating => &pattern::alternating,
+            Sentence => &pattern::sentence,
             Custom { pattern, .. } => *pattern,
 
             #[cfg(feature = "random")]
-            Random => pattern::random,
+            Random => &pattern::random,
             #[cfg(feature = "random")]
-            PseudoRandom => pattern::pseudo_random,
+            PseudoRandom => &pattern::pseudo_random,
         }
     }
 
+    /// The name of the case, which is the name of its variant.  Custom cases are
+    /// named `"Custom"`.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!("UpperSnake", Case::UpperSnake.name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.entry().map_or("Custom", |(_, name, _, _)| name)
+    }
+
+    /// Other names the case is parsed from, besides its [name](Case::name).  Variants that
+    /// are [alternatives](Case::canonical) of this case are not included.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(&["ScreamingSnake"], Case::Constant.aliases());
+    /// assert!(Case::UpperSnake.aliases().is_empty());
+    /// ```
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.entry().map_or(&[], |(_, _, aliases, _)| aliases)
+    }
+
+    /// The string `"My variable NAME"` converted into this case, or `None` for custom
+    /// cases.  Random cases give one possible conversion.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(Some("my-variable-name"), Case::Kebab.example());
+    /// ```
+    ///
+    /// Together with [`name`](Case::name) and [`aliases`](Case::aliases), this
+    /// describes every case, such as in the help of a command line tool.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// for case in Case::all_cases() {
+    ///     println!("{:<12} {}", case.name(), case.example().unwrap());
+    /// }
+    /// ```
+    pub fn example(&self) -> Option<&'static str> {
+        self.entry().map(|(_, _, _, example)| *example)
+    }
+
+    fn entry(&self) -> Option<&'static NameEntry> {
+        NAMES.iter().find(|(case, _, _, _)| case == self)
+    }
+
+    /// The variant that this case is an alternative name for, or itself.  The variants
+    /// [`UpperSnake`](Case::UpperSnake), [`UpperKebab`](Case::UpperKebab) and
+    /// [`UpperCamel`](Case::UpperCamel) behave the same as [`Constant`](Case::Constant),
+    /// [`Cobol`](Case::Cobol) and [`Pascal`](Case::Pascal), but are not equal to them.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_ne!(Case::UpperSnake, Case::Constant);
+    /// assert_eq!(Case::Constant, Case::UpperSnake.canonical());
+    /// assert_eq!(Case::Snake, Case::Snake.canonical());
+    /// ```
+    pub const fn canonical(&self) -> Case<'a> {
+        match self {
+            Case::UpperSnake => Case::Constant,
+            Case::UpperKebab => Case::Cobol,
+            Case::UpperCamel => Case::Pascal,
+            _ => *self,
+        }
+    }
+
+    /// The family of the case, which is shared by cases with the same boundaries and
+    /// delimiter.  Cases in the same family differ only in their pattern.
+    /// ```
+    /// use convert_case::{Case, CaseFamily};
+    ///
+    /// assert_eq!(CaseFamily::Snake, Case::Ada.family());
+    /// assert_eq!(CaseFamily::Camel, Case::Pascal.family());
+    /// ```
+    pub const fn family(&self) -> CaseFamily {
+        use Case::*;
+        match self {
+            Snake | Constant | UpperSnake | Ada => CaseFamily::Snake,
+            Kebab | Cobol | UpperKebab | Train => CaseFamily::Kebab,
+            Camel | UpperCamel | Pascal => CaseFamily::Camel,
+            Flat | UpperFlat => CaseFamily::Flat,
+            Upper | Lower | Title | Sentence | Toggle | Alternating => CaseFamily::Space,
+            Dot | UpperDot => CaseFamily::Dot,
+            Path => CaseFamily::Path,
+            Namespace => CaseFamily::Namespace,
+            Custom { .. } => CaseFamily::Custom,
+
+            #[cfg(feature = "random")]
+            Random | PseudoRandom => CaseFamily::Space,
+        }
+    }
+
+    /// The built-in case that joins words mutated by `pattern` with `delim`, if there is
+    /// one.  Alternative variants like [`UpperSnake`](Case::UpperSnake) are never returned,
+    /// only their [canonical](Case::canonical) case.
+    /// ```
+    /// use convert_case::{pattern, Case};
+    ///
+    /// assert_eq!(Some(Case::Constant), Case::from_parts(&pattern::uppercase, "_"));
+    /// assert_eq!(Some(Case::Pascal), Case::from_parts(&pattern::capital, ""));
+    /// assert_eq!(None, Case::from_parts(&pattern::camel, "-"));
+    /// ```
+    pub fn from_parts(pattern: &dyn Pattern, delim: &str) -> Option<Case<'static>> {
+        Case::all_cases()
+            .iter()
+            .find(|case| case.delim() == delim && pattern::same(case.pattern(), pattern))
+            .copied()
+    }
+
     /// Split an identifier into words based on the boundaries of this case.
     /// ```
     /// use convert_case::Case;
@@ -407,6 +628,37 @@ impl Case<'_> {
         boundary::split(s, self.boundaries())
     }
 
+    /// Lazily split an identifier into words based on the boundaries of this case.
+    /// See [`split_iter`](boundary::split_iter) for details.
+    /// ```
+    /// use convert_case::Case;
+    /// let mut words = Case::Snake.split_iter(&"get_total_length");
+    /// assert_eq!(Some("get"), words.next());
+    /// assert_eq!(vec!["total", "length"], words.collect::<Vec<_>>());
+    /// ```
+    pub fn split_iter<T>(self, s: &T) -> SplitIter<'_, 'a>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        boundary::split_iter(s, self.boundaries())
+    }
+
+    /// Split an identifier into words based on the boundaries of this case, along with
+    /// where each word came from.  See [`split_spans`](boundary::split_spans) for details.
+    /// ```
+    /// use convert_case::{Boundary, Case};
+    /// let spans: Vec<_> = Case::Kebab.split_spans(&"get-total").collect();
+    /// assert_eq!(0..3, spans[0].range);
+    /// assert_eq!(Some(&Boundary::HYPHEN), spans[0].boundary);
+    /// assert_eq!(4..9, spans[1].range);
+    /// ```
+    pub fn split_spans<T>(self, s: &T) -> SplitSpans<'_, 'a>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        boundary::split_spans(s, self.boundaries())
+    }
+
     /// Mutate a list of words based on the pattern of this case.
     /// ```
     /// use convert_case::Case;
@@ -416,7 +668,7 @@ impl Case<'_> {
     /// );
     /// ```
     pub fn mutate(self, words: &[&str]) -> Vec<String> {
-        (self.pattern())(words)
+        self.pattern().mutate(words)
     }
 
     /// Join a list of words into a single identifier using the delimiter of this case.
@@ -435,6 +687,25 @@ impl Case<'_> {
         words.join(self.delim())
     }
 
+    /// Detects which of the [deterministic cases](Case::deterministic_cases) an identifier
+    /// could be in, ranked by confidence.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// let detection = Case::detect(&"MY_VARIABLE");
+    /// assert_eq!(Some(Case::Constant), detection.best());
+    ///
+    /// let detection = Case::detect(&"FOO");
+    /// assert!(detection.is_ambiguous());
+    /// assert_eq!(0.2, detection.confidence(Case::Constant));
+    /// ```
+    pub fn detect<T>(s: &T) -> Detection<'static>
+    where
+        T: AsRef<str>,
+    {
+        detect::detect(s.as_ref())
+    }
+
     /// Array of all non-custom case enum variants.  Does not include aliases.
     pub fn all_cases() -> &'static [Case<'static>] {
         use Case::*;
@@ -455,6 +726,10 @@ impl Case<'_> {
             Sentence,
             Alternating,
             Toggle,
+            Dot,
+            UpperDot,
+            Path,
+            Namespace,
             #[cfg(feature = "random")]
             Random,
             #[cfg(feature = "random")]
@@ -491,6 +766,481 @@ impl Case<'_> {
             Sentence,
             Alternating,
             Toggle,
+            Dot,
+            UpperDot,
+            Path,
+            Namespace,
         ]
     }
 }
+
+/// A group of cases that share boundaries and a delimiter, and differ only in their
+/// pattern.  Each family is named after the most common case within it.
+///
+/// | Family | Cases |
+/// | --- | --- |
+/// | Snake | Snake, Constant, UpperSnake, Ada |
+/// | Kebab | Kebab, Cobol, UpperKebab, Train |
+/// | Camel | Camel, Pascal, UpperCamel |
+/// | Flat | Flat, UpperFlat |
+/// | Space | Lower, Upper, Title, Sentence, Alternating, Toggle, Random, PseudoRandom |
+/// | Dot | Dot, UpperDot |
+/// | Path | Path |
+/// | Namespace | Namespace |
+/// | Custom | [`Case::Custom`] |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseFamily {
+    /// Words split and joined by underscores.
+    Snake,
+    /// Words split and joined by hyphens.
+    Kebab,
+    /// Words split by changes in capitalization and joined without a delimiter.
+    Camel,
+    /// Words joined without a delimiter, and never split.
+    Flat,
+    /// Words split and joined by spaces.
+    Space,
+    /// Words split and joined by periods.
+    Dot,
+    /// Words split and joined by slashes.
+    Path,
+    /// Words split and joined by double colons.
+    Namespace,
+    /// Custom cases, which are not grouped.
+    Custom,
+}
+
+impl CaseFamily {
+    /// The built-in cases in the family, including alternative variants.
+    /// ```
+    /// use convert_case::{Case, CaseFamily};
+    ///
+    /// assert_eq!(&[Case::Flat, Case::UpperFlat], CaseFamily::Flat.cases());
+    /// ```
+    pub fn cases(self) -> &'static [Case<'static>] {
+        use Case::*;
+        match self {
+            CaseFamily::Snake => &[Snake, Constant, UpperSnake, Ada],
+            CaseFamily::Kebab => &[Kebab, Cobol, UpperKebab, Train],
+            CaseFamily::Camel => &[Camel, Pascal, UpperCamel],
+            CaseFamily::Flat => &[Flat, UpperFlat],
+            CaseFamily::Space => &[
+                Lower,
+                Upper,
+                Title,
+                Sentence,
+                Alternating,
+                Toggle,
+                #[cfg(feature = "random")]
+                Random,
+                #[cfg(feature = "random")]
+                PseudoRandom,
+            ],
+            CaseFamily::Dot => &[Dot, UpperDot],
+            CaseFamily::Path => &[Path],
+            CaseFamily::Namespace => &[Namespace],
+            CaseFamily::Custom => &[],
+        }
+    }
+}
+
+const DOT: &[Boundary] = &[Boundary::from_delim(".")];
+const SLASH: &[Boundary] = &[Boundary::from_delim("/")];
+const DOUBLE_COLON: &[Boundary] = &[Boundary::from_delim("::")];
+
+type NameEntry = (
+    Case<'static>,
+    &'static str,
+    &'static [&'static str],
+    &'static str,
+);
+
+/// The name of each variant followed by its aliases and an example.  Names are
+/// matched ignoring capitalization, separators and a trailing "case".
+const NAMES: &[NameEntry] = &[
+    (Case::Snake, "Snake", &[], "my_variable_name"),
+    (
+        Case::Constant,
+        "Constant",
+        &["ScreamingSnake"],
+        "MY_VARIABLE_NAME",
+    ),
+    (Case::UpperSnake, "UpperSnake", &[], "MY_VARIABLE_NAME"),
+    (Case::Ada, "Ada", &[], "My_Variable_Name"),
+    (Case::Kebab, "Kebab", &["Dash"], "my-variable-name"),
+    (
+        Case::Cobol,
+        "Cobol",
+        &["ScreamingKebab"],
+        "MY-VARIABLE-NAME",
+    ),
+    (Case::UpperKebab, "UpperKebab", &[], "MY-VARIABLE-NAME"),
+    (Case::Train, "Train", &[], "My-Variable-Name"),
+    (Case::Flat, "Flat", &[], "myvariablename"),
+    (Case::UpperFlat, "UpperFlat", &[], "MYVARIABLENAME"),
+    (Case::Pascal, "Pascal", &[], "MyVariableName"),
+    (Case::UpperCamel, "UpperCamel", &[], "MyVariableName"),
+    (Case::Camel, "Camel", &["LowerCamel"], "myVariableName"),
+    (Case::Lower, "Lower", &[], "my variable name"),
+    (Case::Upper, "Upper", &[], "MY VARIABLE NAME"),
+    (Case::Title, "Title", &[], "My Variable Name"),
+    (Case::Sentence, "Sentence", &[], "My variable name"),
+    (Case::Alternating, "Alternating", &[], "mY vArIaBlE nAmE"),
+    (Case::Toggle, "Toggle", &[], "mY vARIABLE nAME"),
+    (Case::Dot, "Dot", &[], "my.variable.name"),
+    (
+        Case::UpperDot,
+        "UpperDot",
+        &["ScreamingDot"],
+        "MY.VARIABLE.NAME",
+    ),
+    (Case::Path, "Path", &["Slash"], "my/variable/name"),
+    (
+        Case::Namespace,
+        "Namespace",
+        &["DoubleColon"],
+        "My::Variable::Name",
+    ),
+    #[cfg(feature = "random")]
+    (Case::Random, "Random", &[], "My vaRIAbLE nAme"),
+    #[cfg(feature = "random")]
+    (Case::PseudoRandom, "PseudoRandom", &[], "mY vArIAblE NamE"),
+];
+
+/// Lowercases a case name and removes separators and a trailing "case", so that
+/// `"kebab-case"`, `"Kebab"` and `"KEBAB_CASE"` are all read as `"kebab"`.
+pub(crate) fn normalize_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect();
+    match name.strip_suffix("case") {
+        Some(stripped) if !stripped.is_empty() => String::from(stripped),
+        _ => name,
+    }
+}
+
+/// Custom cases are equal when they have the same boundaries, delimiter and pattern.
+/// Patterns are compared by type, and patterns that hold data are also compared by address.
+impl PartialEq for Case<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Case::Custom {
+                    boundaries,
+                    pattern,
+                    delim,
+                },
+                Case::Custom {
+                    boundaries: other_boundaries,
+                    pattern: other_pattern,
+                    delim: other_delim,
+                },
+            ) => {
+                boundaries == other_boundaries
+                    && delim == other_delim
+                    && pattern::same(*pattern, *other_pattern)
+            }
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Case<'_> {}
+
+impl Hash for Case<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        if let Case::Custom {
+            boundaries,
+            pattern,
+            delim,
+        } = self
+        {
+            boundaries.hash(state);
+            (pattern::inner(*pattern) as &dyn Any).type_id().hash(state);
+            delim.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for Case<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Case::Custom {
+                boundaries, delim, ..
+            } => f
+                .debug_struct("Custom")
+                .field("boundaries", boundaries)
+                .field("delim", delim)
+                .finish_non_exhaustive(),
+            _ => fmt::Display::fmt(self, f),
+        }
+    }
+}
+
+/// Displays the name of the case, which is the name of the variant.  Custom
+/// cases are displayed by their delimiter.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!("Snake", Case::Snake.to_string());
+/// assert_eq!("UpperCamel", Case::UpperCamel.to_string());
+/// ```
+impl fmt::Display for Case<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Case::Custom { delim, .. } = self {
+            return write!(f, "Custom({:?})", delim);
+        }
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a case from its name or one of its aliases.  Matching ignores
+/// capitalization, the separators `_`, `-` and space, and a trailing "case".
+///
+/// | Case | Accepted aliases |
+/// | --- | --- |
+/// | Constant | ScreamingSnake |
+/// | Kebab | Dash |
+/// | Cobol | ScreamingKebab |
+/// | Camel | LowerCamel |
+/// | UpperDot | ScreamingDot |
+/// | Path | Slash |
+/// | Namespace | DoubleColon |
+///
+/// The alternative names [`UpperSnake`](Case::UpperSnake), [`UpperKebab`](Case::UpperKebab)
+/// and [`UpperCamel`](Case::UpperCamel) parse into their own variants.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!(Ok(Case::Snake), "snake".parse());
+/// assert_eq!(Ok(Case::Kebab), "kebab-case".parse());
+/// assert_eq!(Ok(Case::Constant), "SCREAMING_SNAKE".parse());
+/// assert_eq!(Ok(Case::UpperCamel), "UpperCamel".parse());
+/// assert_eq!(Ok(Case::UpperDot), "SCREAMING_DOT".parse());
+/// assert!("spongebob".parse::<Case>().is_err());
+/// ```
+impl FromStr for Case<'_> {
+    type Err = ParseCaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = normalize_name(s);
+        NAMES
+            .iter()
+            .find(|(_, name, aliases, _)| {
+                normalize_name(name) == input || aliases.iter().any(|a| normalize_name(a) == input)
+            })
+            .map(|(case, _, _, _)| *case)
+            .ok_or_else(|| ParseCaseError::new(s))
+    }
+}
+
+/// The error returned when parsing a [`Case`] from an unknown name.
+/// ```
+/// use convert_case::Case;
+///
+/// let err = "spongebob".parse::<Case>().unwrap_err();
+/// assert_eq!("spongebob", err.input());
+/// assert!(err.to_string().starts_with("unknown case \"spongebob\", expected one of: Snake, "));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseError {
+    input: String,
+    /// Names of the registered cases, when parsed by a [`CaseRegistry`](crate::CaseRegistry).
+    pub(crate) registered: Vec<String>,
+}
+
+impl ParseCaseError {
+    pub(crate) fn new(input: &str) -> Self {
+        ParseCaseError {
+            input: String::from(input),
+            registered: Vec::new(),
+        }
+    }
+
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown case {:?}, expected one of: ", self.input)?;
+        for (i, (_, name, aliases, _)) in NAMES.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+            for alias in aliases.iter() {
+                write!(f, ", {}", alias)?;
+            }
+        }
+        for name in &self.registered {
+            write!(f, ", {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseCaseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::Casing;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn display_round_trips() {
+        for (case, _, _, _) in NAMES {
+            assert_eq!(Ok(*case), case.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn parse_ignores_style() {
+        for name in [
+            "upper_snake",
+            "UPPER-SNAKE",
+            "upper snake case",
+            "UpperSnakeCase",
+        ] {
+            assert_eq!(Ok(Case::UpperSnake), name.parse());
+        }
+    }
+
+    #[test]
+    fn parse_aliases() {
+        assert_eq!(Ok(Case::Constant), "screaming-snake-case".parse());
+        assert_eq!(Ok(Case::Cobol), "SCREAMING-KEBAB".parse());
+        assert_eq!(Ok(Case::Kebab), "dash".parse());
+        assert_eq!(Ok(Case::Camel), "lowerCamelCase".parse());
+    }
+
+    #[test]
+    fn parse_case_alone_is_unknown() {
+        assert!("case".parse::<Case>().is_err());
+        assert!("".parse::<Case>().is_err());
+    }
+
+    #[test]
+    fn display_custom() {
+        let custom = Case::Custom {
+            boundaries: &[],
+            pattern: &crate::pattern::lowercase,
+            delim: ".",
+        };
+        assert_eq!("Custom(\".\")", custom.to_string());
+    }
+
+    #[test]
+    fn custom_equality() {
+        let dot = |pattern| Case::Custom {
+            boundaries: &[],
+            pattern,
+            delim: ".",
+        };
+        assert_eq!(dot(&pattern::lowercase), dot(&pattern::lowercase));
+        assert_ne!(dot(&pattern::lowercase), dot(&pattern::uppercase));
+        assert_ne!(dot(&pattern::lowercase), Case::Snake);
+        assert_eq!(Case::Snake, Case::Snake);
+        assert_ne!(Case::Snake, Case::Kebab);
+    }
+
+    #[test]
+    fn custom_boxed_pattern() {
+        let prefix = String::from("x");
+        let prefixed: Box<dyn Pattern> = Box::new(move |words: &[&str]| {
+            let mut words = pattern::lowercase(words);
+            words.insert(0, prefix.clone());
+            words
+        });
+        let header = Case::Custom {
+            boundaries: &Boundary::defaults(),
+            pattern: &prefixed,
+            delim: "-",
+        };
+        assert_eq!("x-request-id", "RequestId".to_case(header));
+        assert_eq!(header, header);
+    }
+
+    #[test]
+    fn delimited_cases() {
+        let s = "XMLHttpRequest";
+        assert_eq!("xml.http.request", s.to_case(Case::Dot));
+        assert_eq!("XML.HTTP.REQUEST", s.to_case(Case::UpperDot));
+        assert_eq!("xml/http/request", s.to_case(Case::Path));
+        assert_eq!("Xml::Http::Request", s.to_case(Case::Namespace));
+
+        assert_eq!(
+            "my_module_item",
+            "my.module.item".from_case(Case::Dot).to_case(Case::Snake)
+        );
+        assert_eq!(
+            "std_io_bufreader",
+            "std::io::BufReader"
+                .from_case(Case::Namespace)
+                .to_case(Case::Snake)
+        );
+        assert_eq!(
+            "src-lib-rs",
+            "src/lib/rs".from_case(Case::Path).to_case(Case::Kebab)
+        );
+    }
+
+    #[test]
+    fn metadata_covers_every_case() {
+        for &case in Case::all_cases() {
+            assert_eq!(case, case.canonical());
+            assert!(case.family().cases().contains(&case), "{:?}", case);
+            assert_eq!(Some(case), Case::from_parts(case.pattern(), case.delim()));
+            assert_eq!(Ok(case), case.name().parse());
+            for alias in case.aliases() {
+                assert_eq!(Ok(case), alias.parse());
+            }
+        }
+        for &case in Case::deterministic_cases() {
+            assert_eq!(
+                case.example(),
+                Some("My variable NAME".to_case(case).as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn alternative_variants() {
+        for (alt, case) in [
+            (Case::UpperSnake, Case::Constant),
+            (Case::UpperKebab, Case::Cobol),
+            (Case::UpperCamel, Case::Pascal),
+        ] {
+            assert_eq!(case, alt.canonical());
+            assert_eq!(case.family(), alt.family());
+            assert_eq!(case.example(), alt.example());
+            assert_eq!(Some(case), Case::from_parts(alt.pattern(), alt.delim()));
+        }
+    }
+
+    #[test]
+    fn custom_metadata() {
+        let custom = Case::Custom {
+            boundaries: &[],
+            pattern: &pattern::lowercase,
+            delim: "+",
+        };
+        assert_eq!("Custom", custom.name());
+        assert_eq!(None, custom.example());
+        assert_eq!(CaseFamily::Custom, custom.family());
+        assert_eq!(None, Case::from_parts(custom.pattern(), custom.delim()));
+    }
+
+    #[test]
+    fn debug_names() {
+        assert_eq!("UpperSnake", format!("{:?}", Case::UpperSnake));
+    }
+}
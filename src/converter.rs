@@ -1,18 +1,25 @@
+use crate::acronym::{AcronymStyle, DEFAULT_INITIALISMS};
 use crate::boundary;
-use crate::boundary::Boundary;
+use crate::boundary::{Boundary, Words};
 use crate::pattern;
-use crate::pattern::Pattern;
-use crate::Case;
+use crate::pattern::{Capitalize, Pattern, WordWriter};
+use crate::{Case, Locale};
 
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::{fmt, slice};
 
 /// The parameters for performing a case conversion.
 ///
-/// A `Converter` stores three fields needed for case conversion.
+/// A `Converter` stores the fields needed for case conversion.
 /// 1) `boundaries`: how a string is segmented into _words_.
-/// 2) `pattern`: how words are mutated, or how each character's case will change.
-/// 3) `delim` or delimeter: how the mutated words are joined into the final string.
+/// 2) `lexicon`: words that are never segmented, like `"GraphQL"`.
+/// 3) `pattern`: how words are mutated, or how each character's case will change.
+/// 4) `initialisms` and `acronym_style`: how initialisms like `"HTTP"` are mutated.
+/// 5) `locale` and `capitalize`: how the built-in patterns change the case of letters.
+/// 6) `delim` or delimeter: how the mutated words are joined into the final string.
 ///
 /// Then calling [`convert`](Converter::convert) on a `Converter` will apply a case conversion
 /// defined by those fields.  The `Converter` struct is what is used underneath those functions
@@ -61,10 +68,33 @@ pub struct Converter {
     /// How a string is segmented into words.
     pub boundaries: Vec<Boundary>,
 
+    /// Words that are kept whole when segmenting, even if a boundary is present within
+    /// them.  See [`SplitSpans::with_lexicon`](crate::SplitSpans::with_lexicon).
+    pub lexicon: Vec<Cow<'static, str>>,
+
     /// How each word is mutated before joining.  In the case that there is no pattern, none of the
     /// words will be mutated before joining and will maintain whatever case they were in the
     /// original string.
-    pub pattern: Pattern,
+    pub pattern: Box<dyn Pattern>,
+
+    /// Words that are rewritten according to `acronym_style` after the pattern is applied.
+    /// Words are compared to initialisms ignoring case.
+    pub initialisms: Vec<Cow<'static, str>>,
+
+    /// How initialisms are written when the pattern capitalizes them.
+    pub acronym_style: AcronymStyle,
+
+    /// The language whose case mappings are followed by the built-in patterns.
+    pub locale: Locale,
+
+    /// Which letter of each word is capitalized by the built-in patterns.
+    pub capitalize: Capitalize,
+
+    /// The seed for the random number generator used by the [`random`](pattern::random)
+    /// and [`pseudo_random`](pattern::pseudo_random) patterns.  When `None`, the thread-local
+    /// generator is used.
+    #[cfg(feature = "random")]
+    pub seed: Option<u64>,
 
     /// The string used to join mutated words together.
     pub delim: String,
@@ -74,7 +104,17 @@ impl Default for Converter {
     fn default() -> Self {
         Converter {
             boundaries: Boundary::defaults().to_vec(),
-            pattern: pattern::noop,
+            lexicon: Vec::new(),
+            pattern: Box::new(pattern::noop),
+            initialisms: DEFAULT_INITIALISMS
+                .iter()
+                .map(|&w| Cow::Borrowed(w))
+                .collect(),
+            acronym_style: AcronymStyle::Capitalized,
+            locale: Locale::Root,
+            capitalize: Capitalize::FirstGrapheme,
+            #[cfg(feature = "random")]
+            seed: None,
             delim: String::new(),
         }
     }
@@ -82,8 +122,10 @@ impl Default for Converter {
 
 impl Converter {
     /// Creates a new `Converter` with default fields.  This is the same as `Default::default()`.
-    /// The `Converter` will use `Boundary::defaults()` for boundaries, no pattern, and an empty
-    /// string as a delimeter.
+    /// The `Converter` will use `Boundary::defaults()` for boundaries, an empty lexicon,
+    /// no pattern, [`DEFAULT_INITIALISMS`] capitalized like any other word,
+    /// the [root](Locale::Root) locale, capitalizing the
+    /// [first grapheme](Capitalize::FirstGrapheme) of words, and an empty string as a delimeter.
     /// ```
     /// # use convert_case::Converter;
     /// let conv = Converter::new();
@@ -104,21 +146,125 @@ impl Converter {
     where
         T: AsRef<str>,
     {
-        // TODO: if I change AsRef -> Borrow or ToString, fix here
-        let words = boundary::split(&s, &self.boundaries);
-        let words = words.iter().map(|s| s.as_ref()).collect::<Vec<&str>>();
-        (self.pattern)(&words).join(&self.delim)
+        let s = s.as_ref();
+        let mut converted = String::with_capacity(s.len());
+        // Writing into a string never fails
+        let _ = self.convert_into(s, &mut converted);
+        converted
     }
 
-    /// Set the pattern and delimiter to those associated with the given case.
+    /// Converts a string and writes the result into `out`, such as a `String` that is
+    /// reused between conversions.
+    ///
+    /// With the patterns in the [`pattern`] module, the default [`Locale`] and
+    /// [`Capitalize`], words are mutated as they are written, so nothing is allocated
+    /// besides what `out` allocates.  Other patterns are written with
+    /// [`Pattern::mutate_into`].  Otherwise the words are collected and mutated as
+    /// in [`convert`](Converter::convert).
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// let mut buf = String::new();
+    /// for s in ["FirstName", "LastName"] {
+    ///     buf.clear();
+    ///     conv.convert_into(s, &mut buf).unwrap();
+    ///     assert!(buf.ends_with("_name"));
+    /// }
+    /// ```
+    pub fn convert_into<T, W>(&self, s: T, out: &mut W) -> fmt::Result
+    where
+        T: AsRef<str>,
+        W: fmt::Write,
+    {
+        self.as_borrowed().convert_into(s, out)
+    }
+
+    /// Converts a string, borrowing it when it is already in the converted form.
+    ///
+    /// The conversion is compared against `s` as it is written, and a copy is only
+    /// started at the first difference, so a string that is unchanged is never copied
+    /// and the string is converted only once.
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// use std::borrow::Cow;
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!(Cow::Borrowed("user_id"), conv.convert_cow("user_id"));
+    /// assert_eq!(Cow::<str>::Owned("user_id".to_string()), conv.convert_cow("userId"));
+    /// ```
+    pub fn convert_cow<'s, T>(&self, s: &'s T) -> Cow<'s, str>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        self.as_borrowed().convert_cow(s)
+    }
+
+    /// Converts a string in place, returning whether it changed.
+    ///
+    /// A string that is already in the converted form is left untouched, without
+    /// allocating.  Otherwise the converted string is written over it, reusing the
+    /// existing allocation when the result fits in its capacity.
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// let mut name = String::from("userId");
+    /// assert!(conv.convert_in_place(&mut name));
+    /// assert_eq!("user_id", name);
+    /// assert!(!conv.convert_in_place(&mut name));
+    /// ```
+    pub fn convert_in_place(&self, s: &mut String) -> bool {
+        self.as_borrowed().convert_in_place(s)
+    }
+
+    /// Returns a wrapper that converts `s` when it is formatted, without creating
+    /// an intermediate `String`.  See [`Converted`].
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!("fn get_total()", format!("fn {}()", conv.display(&"getTotal")));
+    /// ```
+    pub fn display<'a, T>(&'a self, s: &'a T) -> Converted<'a, T>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        Converted {
+            s,
+            conv: self.as_borrowed(),
+        }
+    }
+
+    /// Borrows the fields of this converter as a [`BorrowedConverter`].
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new().to_case(Case::Kebab);
+    /// assert_eq!(conv.convert("MyVarName"), conv.as_borrowed().convert("MyVarName"));
+    /// ```
+    pub fn as_borrowed(&self) -> BorrowedConverter<'_> {
+        BorrowedConverter {
+            boundaries: &self.boundaries,
+            lexicon: Words::Owned(&self.lexicon),
+            pattern: &*self.pattern,
+            initialisms: Words::Owned(&self.initialisms),
+            acronym_style: self.acronym_style,
+            locale: self.locale,
+            capitalize: self.capitalize,
+            #[cfg(feature = "random")]
+            seed: self.seed,
+            delim: &self.delim,
+        }
+    }
+
+    /// Set the pattern and delimiter to those associated with the given case.  The
+    /// converter owns its pattern, so a [`Case::Custom`] must borrow a `'static` one;
+    /// use [`BorrowedConverter::to_case`] for custom cases with a shorter lifetime.
     /// ```
     /// # use convert_case::{Case, Converter};
     /// let conv = Converter::new()
     ///     .to_case(Case::Pascal);
     /// assert_eq!("VariableName", conv.convert("variable name"))
     /// ```
-    pub fn to_case(mut self, case: Case) -> Self {
-        self.pattern = case.pattern();
+    pub fn to_case(mut self, case: Case<'static>) -> Self {
+        self.pattern = Box::new(case.pattern());
         self.delim = case.delim().to_string();
         self
     }
@@ -174,7 +320,7 @@ impl Converter {
     /// assert_eq!("2020 10 First Day", conv.convert("2020-10_firstDay"));
     /// ```
     pub fn add_boundaries(mut self, bs: &[Boundary]) -> Self {
-        self.boundaries.extend(bs);
+        self.boundaries.extend_from_slice(bs);
         self
     }
 
@@ -187,7 +333,7 @@ impl Converter {
     /// assert_eq!("httprequest-parser", conv.convert("HTTPRequest_parser"));
     /// ```
     pub fn remove_boundary(mut self, b: Boundary) -> Self {
-        self.boundaries.retain(|&x| x != b);
+        self.boundaries.retain(|x| *x != b);
         self
     }
 
@@ -201,11 +347,173 @@ impl Converter {
     /// ```
     pub fn remove_boundaries(mut self, bs: &[Boundary]) -> Self {
         for b in bs {
-            self.boundaries.retain(|&x| x != *b);
+            self.boundaries.retain(|x| x != b);
         }
         self
     }
 
+    /// Sets the lexicon to the words provided.
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new()
+    ///     .set_lexicon(&["DynamoDB"])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("dynamodb_table", conv.convert("DynamoDBTable"));
+    /// assert_eq!("graph_ql_query", conv.convert("GraphQLQuery"));
+    /// ```
+    pub fn set_lexicon<T>(mut self, words: &[T]) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.lexicon = words
+            .iter()
+            .map(|w| Cow::Owned(w.as_ref().to_string()))
+            .collect();
+        self
+    }
+
+    /// Adds the words provided to the lexicon, such as the common words in
+    /// [`DEFAULT_LEXICON`](crate::DEFAULT_LEXICON).
+    /// ```
+    /// # use convert_case::{Case, Converter, DEFAULT_LEXICON};
+    /// let conv = Converter::new()
+    ///     .add_lexicon(DEFAULT_LEXICON)
+    ///     .add_lexicon(&["DynamoDB"])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("dynamodb_table", conv.convert("DynamoDBTable"));
+    /// assert_eq!("graphql_query", conv.convert("GraphQLQuery"));
+    /// ```
+    pub fn add_lexicon<T>(mut self, words: &[T]) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.lexicon
+            .extend(words.iter().map(|w| Cow::Owned(w.as_ref().to_string())));
+        self
+    }
+
+    /// Empties the lexicon, so words are split wherever a boundary is present.
+    /// ```
+    /// # use convert_case::{Case, Converter, DEFAULT_LEXICON};
+    /// let conv = Converter::new()
+    ///     .add_lexicon(DEFAULT_LEXICON)
+    ///     .remove_lexicon()
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("i_os_app", conv.convert("iOSApp"));
+    /// ```
+    pub fn remove_lexicon(mut self) -> Self {
+        self.lexicon = Vec::new();
+        self
+    }
+
+    /// Sets how initialisms are written when the pattern capitalizes them.
+    /// ```
+    /// # use convert_case::{AcronymStyle, Case, Converter};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_acronym_style(AcronymStyle::Upper);
+    /// assert_eq!("ServeHTTPRequest", conv.convert("serve_http_request"));
+    /// ```
+    pub fn set_acronym_style(mut self, style: AcronymStyle) -> Self {
+        self.acronym_style = style;
+        self
+    }
+
+    /// Sets the initialisms to the words provided.
+    /// ```
+    /// # use convert_case::{AcronymStyle, Case, Converter};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_acronym_style(AcronymStyle::Upper)
+    ///     .set_initialisms(&["AWS"]);
+    /// assert_eq!("AWSHttpClient", conv.convert("aws_http_client"));
+    /// ```
+    pub fn set_initialisms<T>(mut self, words: &[T]) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.initialisms = words
+            .iter()
+            .map(|w| Cow::Owned(w.as_ref().to_string()))
+            .collect();
+        self
+    }
+
+    /// Adds the words provided to the initialisms.
+    /// ```
+    /// # use convert_case::{AcronymStyle, Case, Converter};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_acronym_style(AcronymStyle::Upper)
+    ///     .add_initialisms(&["AWS"]);
+    /// assert_eq!("AWSHTTPClient", conv.convert("aws_http_client"));
+    /// ```
+    pub fn add_initialisms<T>(mut self, words: &[T]) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.initialisms
+            .extend(words.iter().map(|w| Cow::Owned(w.as_ref().to_string())));
+        self
+    }
+
+    /// Sets the locale whose case mappings are followed by the [`lowercase`](pattern::lowercase),
+    /// [`uppercase`](pattern::uppercase), [`capital`](pattern::capital),
+    /// [`camel`](pattern::camel), [`sentence`](pattern::sentence) and
+    /// [`toggle`](pattern::toggle) patterns.
+    /// ```
+    /// # use convert_case::{Case, Converter, Locale};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Camel)
+    ///     .set_locale(Locale::Turkish);
+    /// assert_eq!("şehirİsmi", conv.convert("ŞEHİR_İSMİ"));
+    /// ```
+    pub fn set_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets which letter of each word is capitalized by the [`capital`](pattern::capital),
+    /// [`camel`](pattern::camel), [`sentence`](pattern::sentence) and
+    /// [`toggle`](pattern::toggle) patterns.
+    /// ```
+    /// # use convert_case::{Case, Converter, pattern::Capitalize};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_capitalize(Capitalize::FirstLetter);
+    /// assert_eq!("#Hash$Tag", conv.convert("#hash $tag"));
+    /// ```
+    pub fn set_capitalize(mut self, capitalize: Capitalize) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
+
+    /// Seeds the random number generator used by the [`random`](pattern::random) and
+    /// [`pseudo_random`](pattern::pseudo_random) patterns, so that converting the same
+    /// string always gives the same result.  The output for a given seed may change
+    /// with new versions of the `rand` crate.  Only available with the "random" feature.
+    /// ```
+    /// # use convert_case::{Case, Converter};
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Random)
+    ///     .set_seed(1234);
+    /// assert_eq!(conv.convert("seeded random case"), conv.convert("seeded random case"));
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Uses the thread-local random number generator for the [`random`](pattern::random)
+    /// and [`pseudo_random`](pattern::pseudo_random) patterns.  This is the default.
+    /// Only available with the "random" feature.
+    #[cfg(feature = "random")]
+    pub fn remove_seed(mut self) -> Self {
+        self.seed = None;
+        self
+    }
+
     /// Sets the delimeter.
     /// ```
     /// # use convert_case::{Case, Converter};
@@ -235,16 +543,27 @@ impl Converter {
         self
     }
 
-    /// Sets the pattern.
+    /// Sets the pattern, which can be a function from the [`pattern`] module, a closure,
+    /// or any other [`Pattern`].
     /// ```
     /// # use convert_case::{Case, Converter, pattern};
     /// let conv = Converter::new()
     ///     .set_delim("_")
     ///     .set_pattern(pattern::sentence);
     /// assert_eq!("Bjarne_case", conv.convert("BJARNE CASE"));
+    ///
+    /// let suffix = String::from("Impl");
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_pattern(move |words: &[&str]| {
+    ///         let mut words = pattern::capital(words);
+    ///         words.push(suffix.clone());
+    ///         words
+    ///     });
+    /// assert_eq!("FileReaderImpl", conv.convert("file_reader"));
     /// ```
-    pub fn set_pattern(mut self, p: Pattern) -> Self {
-        self.pattern = p;
+    pub fn set_pattern<P: Pattern>(mut self, p: P) -> Self {
+        self.pattern = Box::new(p);
         self
     }
 
@@ -259,15 +578,666 @@ impl Converter {
     /// assert_eq!("KoRn_Alone_I_Break", conv.convert("KoRn Alone I Break"));
     /// ```
     pub fn remove_pattern(mut self) -> Self {
-        self.pattern = pattern::noop;
+        self.pattern = Box::new(pattern::noop);
+        self
+    }
+}
+
+const DEFAULT_BOUNDARIES: &[Boundary] = &Boundary::defaults();
+
+/// A [`Converter`] that borrows its boundaries, lexicon, pattern, initialisms and
+/// delimiter instead of owning them.
+///
+/// Every method that builds a `BorrowedConverter` is a `const fn`, so it can be kept in
+/// a `static` or `const` and reused without allocating anything besides the converted
+/// string.  This is what [`Casing::to_case`](crate::Casing::to_case) uses underneath.
+/// A `Converter` can also be borrowed with [`Converter::as_borrowed`].
+/// ```
+/// use convert_case::{pattern, BorrowedConverter, Boundary, Case};
+///
+/// static SNAKE: BorrowedConverter = BorrowedConverter::new().to_case(Case::Snake);
+/// assert_eq!("xml_http_request", SNAKE.convert("XMLHttpRequest"));
+///
+/// static ENV: BorrowedConverter = BorrowedConverter::new()
+///     .from_case(Case::Snake)
+///     .set_pattern(&pattern::uppercase)
+///     .set_delim("__");
+/// assert_eq!("APP__LOG__LEVEL", ENV.convert("app_log_level"));
+///
+/// const DOT_CAMEL: BorrowedConverter = BorrowedConverter::new()
+///     .set_boundaries(&[Boundary::LOWER_UPPER, Boundary::LOWER_DIGIT])
+///     .set_pattern(&pattern::camel)
+///     .set_delim(".");
+/// assert_eq!("collision.Shape.2d", DOT_CAMEL.convert("CollisionShape2D"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct BorrowedConverter<'a> {
+    boundaries: &'a [Boundary],
+    lexicon: Words<'a>,
+    pattern: &'a dyn Pattern,
+    initialisms: Words<'a>,
+    acronym_style: AcronymStyle,
+    locale: Locale,
+    capitalize: Capitalize,
+    #[cfg(feature = "random")]
+    seed: Option<u64>,
+    delim: &'a str,
+}
+
+impl Default for BorrowedConverter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> BorrowedConverter<'a> {
+    /// Creates a `BorrowedConverter` with the same defaults as [`Converter::new`].
+    /// ```
+    /// # use convert_case::BorrowedConverter;
+    /// const CONV: BorrowedConverter = BorrowedConverter::new();
+    /// assert_eq!("DeathPerennialQUEST", CONV.convert("Death-Perennial QUEST"))
+    /// ```
+    pub const fn new() -> Self {
+        BorrowedConverter {
+            boundaries: DEFAULT_BOUNDARIES,
+            lexicon: Words::Borrowed(&[]),
+            pattern: &pattern::noop,
+            initialisms: Words::Borrowed(DEFAULT_INITIALISMS),
+            acronym_style: AcronymStyle::Capitalized,
+            locale: Locale::Root,
+            capitalize: Capitalize::FirstGrapheme,
+            #[cfg(feature = "random")]
+            seed: None,
+            delim: "",
+        }
+    }
+
+    /// Converts a string.  See [`Converter::convert`].
+    pub fn convert<T>(&self, s: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        let s = s.as_ref();
+        let mut converted = String::with_capacity(s.len());
+        // Writing into a string never fails
+        let _ = self.convert_into(s, &mut converted);
+        converted
+    }
+
+    /// Converts a string and writes the result into `out`.  See [`Converter::convert_into`].
+    pub fn convert_into<T, W>(&self, s: T, out: &mut W) -> fmt::Result
+    where
+        T: AsRef<str>,
+        W: fmt::Write,
+    {
+        let mut words = boundary::split_iter(&s, self.boundaries).with_words(self.lexicon);
+        if !self.is_tailored() {
+            if let Some(mut writer) = WordWriter::new(self.pattern) {
+                for (i, word) in words.enumerate() {
+                    if i > 0 {
+                        out.write_str(self.delim)?;
+                    }
+                    if self.acronym_style.uppercases(word, self.initialisms) {
+                        let mut mutated = String::new();
+                        writer.write(word, &mut mutated)?;
+                        self.acronym_style.apply(
+                            slice::from_mut(&mut mutated),
+                            self.initialisms,
+                            self.locale,
+                        );
+                        out.write_str(&mutated)?;
+                    } else {
+                        writer.write(word, out)?;
+                    }
+                }
+                return Ok(());
+            }
+            if self.acronym_style == AcronymStyle::Capitalized {
+                return self.pattern.mutate_into(&mut words, self.delim, out);
+            }
+        }
+
+        let words = words.collect::<Vec<&str>>();
+        let mut words = self.mutate(&words);
+        self.acronym_style
+            .apply(&mut words, self.initialisms, self.locale);
+        for (i, word) in words.iter().enumerate() {
+            if i > 0 {
+                out.write_str(self.delim)?;
+            }
+            out.write_str(word)?;
+        }
+        Ok(())
+    }
+
+    /// Converts a string, borrowing it when it is already in the converted form.
+    /// See [`Converter::convert_cow`].
+    pub fn convert_cow<'s, T>(&self, s: &'s T) -> Cow<'s, str>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        let s = s.as_ref();
+        match self.convert_diverging(s) {
+            Some(converted) => Cow::Owned(converted),
+            None => Cow::Borrowed(s),
+        }
+    }
+
+    /// Converts a string in place, returning whether it changed.
+    /// See [`Converter::convert_in_place`].
+    pub fn convert_in_place(&self, s: &mut String) -> bool {
+        if s.len() > IN_PLACE_INLINE {
+            return match self.convert_diverging(s) {
+                Some(converted) if converted.len() <= s.capacity() => {
+                    s.clear();
+                    s.push_str(&converted);
+                    true
+                }
+                Some(converted) => {
+                    *s = converted;
+                    true
+                }
+                None => false,
+            };
+        }
+        // Short strings are copied onto the stack, so the conversion can be
+        // written straight into the buffer of `s`
+        let mut copy = [0; IN_PLACE_INLINE];
+        copy[..s.len()].copy_from_slice(s.as_bytes());
+        let src = match core::str::from_utf8(&copy[..s.len()]) {
+            Ok(src) => src,
+            Err(_) => unreachable!("copied from a str"),
+        };
+        let mut in_place = InPlace {
+            src,
+            out: s,
+            matched: 0,
+            diverged: false,
+        };
+        // Writing into a string never fails
+        let _ = self.convert_into(src, &mut in_place);
+        in_place.finish()
+    }
+
+    /// Converts `s` once, returning `None` when the result is `s` itself.
+    fn convert_diverging(&self, s: &str) -> Option<String> {
+        let mut diverging = Diverging {
+            s,
+            matched: 0,
+            out: None,
+        };
+        // Writing into a string never fails
+        let _ = self.convert_into(s, &mut diverging);
+        diverging.finish()
+    }
+
+    /// Returns a wrapper that converts `s` when it is formatted.  See [`Converted`].
+    pub fn display<T>(&self, s: &'a T) -> Converted<'a, T>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        Converted { s, conv: *self }
+    }
+
+    /// Whether the locale, capitalization or seed change how the patterns mutate words.
+    fn is_tailored(&self) -> bool {
+        let tailored = self.locale != Locale::Root || self.capitalize != Capitalize::FirstGrapheme;
+        #[cfg(feature = "random")]
+        let tailored = tailored || self.seed.is_some();
+        tailored
+    }
+
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        #[cfg(feature = "random")]
+        if let Some(seed) = self.seed {
+            if let Some(words) = pattern::mutate_seeded(self.pattern, words, seed) {
+                return words;
+            }
+        }
+        pattern::mutate_in(self.pattern, words, self.locale, self.capitalize)
+    }
+
+    /// Sets the pattern and delimiter to those associated with the given case.
+    pub const fn to_case(mut self, case: Case<'a>) -> Self {
+        self.pattern = case.pattern();
+        self.delim = case.delim();
+        self
+    }
+
+    /// Sets the boundaries to those associated with the given case.
+    pub const fn from_case(mut self, case: Case<'a>) -> Self {
+        self.boundaries = case.boundaries();
+        self
+    }
+
+    /// Sets the boundaries to those provided.
+    pub const fn set_boundaries(mut self, bs: &'a [Boundary]) -> Self {
+        self.boundaries = bs;
+        self
+    }
+
+    /// Sets the lexicon to the words provided.
+    /// ```
+    /// # use convert_case::{BorrowedConverter, Case};
+    /// const CONV: BorrowedConverter = BorrowedConverter::new()
+    ///     .set_lexicon(&["DynamoDB"])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("dynamodb_table", CONV.convert("DynamoDBTable"));
+    /// ```
+    pub const fn set_lexicon(mut self, words: &'a [&'a str]) -> Self {
+        self.lexicon = Words::Borrowed(words);
+        self
+    }
+
+    /// Empties the lexicon, so words are split wherever a boundary is present.
+    pub const fn remove_lexicon(mut self) -> Self {
+        self.lexicon = Words::Borrowed(&[]);
+        self
+    }
+
+    /// Sets how initialisms are written when the pattern capitalizes them.
+    pub const fn set_acronym_style(mut self, style: AcronymStyle) -> Self {
+        self.acronym_style = style;
+        self
+    }
+
+    /// Sets the initialisms to the words provided.
+    pub const fn set_initialisms(mut self, words: &'a [&'a str]) -> Self {
+        self.initialisms = Words::Borrowed(words);
+        self
+    }
+
+    /// Sets the locale whose case mappings are followed by the built-in patterns.
+    /// See [`Converter::set_locale`].
+    pub const fn set_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
         self
     }
+
+    /// Sets which letter of each word is capitalized by the built-in patterns.
+    /// See [`Converter::set_capitalize`].
+    pub const fn set_capitalize(mut self, capitalize: Capitalize) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
+
+    /// Seeds the random number generator used by the [`random`](pattern::random) and
+    /// [`pseudo_random`](pattern::pseudo_random) patterns.  Only available with the
+    /// "random" feature.  See [`Converter::set_seed`].
+    #[cfg(feature = "random")]
+    pub const fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Uses the thread-local random number generator for the [`random`](pattern::random)
+    /// and [`pseudo_random`](pattern::pseudo_random) patterns.  This is the default.
+    /// Only available with the "random" feature.
+    #[cfg(feature = "random")]
+    pub const fn remove_seed(mut self) -> Self {
+        self.seed = None;
+        self
+    }
+
+    /// Sets the delimeter.
+    pub const fn set_delim(mut self, d: &'a str) -> Self {
+        self.delim = d;
+        self
+    }
+
+    /// Sets the delimeter to an empty string.
+    pub const fn remove_delim(mut self) -> Self {
+        self.delim = "";
+        self
+    }
+
+    /// Sets the pattern, which can be a function from the [`pattern`] module or any other
+    /// [`Pattern`] that outlives the converter.
+    pub const fn set_pattern(mut self, p: &'a dyn Pattern) -> Self {
+        self.pattern = p;
+        self
+    }
+
+    /// Sets the pattern to [`noop`](pattern::noop), so words keep their case.
+    pub const fn remove_pattern(mut self) -> Self {
+        self.pattern = &pattern::noop;
+        self
+    }
+}
+
+/// Checks that what is written matches the rest of a string, failing at the first difference.
+struct Diverging<'s> {
+    s: &'s str,
+    matched: usize,
+    out: Option<String>,
+}
+
+impl Diverging<'_> {
+    /// The converted string, or `None` when it is the original string.
+    fn finish(self) -> Option<String> {
+        match self.out {
+            Some(out) => Some(out),
+            None if self.matched == self.s.len() => None,
+            None => Some(self.s[..self.matched].to_string()),
+        }
+    }
+}
+
+impl fmt::Write for Diverging<'_> {
+    fn write_str(&mut self, part: &str) -> fmt::Result {
+        if let Some(out) = &mut self.out {
+            out.push_str(part);
+        } else if self.s[self.matched..].starts_with(part) {
+            self.matched += part.len();
+        } else {
+            let mut out = String::with_capacity(self.s.len().max(self.matched + part.len()));
+            out.push_str(&self.s[..self.matched]);
+            out.push_str(part);
+            self.out = Some(out);
+        }
+        Ok(())
+    }
+}
+
+/// Inputs to [`BorrowedConverter::convert_in_place`] of at most this many bytes are
+/// converted without allocating when the result fits in their capacity.
+const IN_PLACE_INLINE: usize = 128;
+
+/// Writes over a string that starts out holding `src`, leaving the matching prefix
+/// untouched and only overwriting from the first difference.
+struct InPlace<'s> {
+    src: &'s str,
+    out: &'s mut String,
+    matched: usize,
+    diverged: bool,
+}
+
+impl InPlace<'_> {
+    /// Truncates what was not rewritten, returning whether the string changed.
+    fn finish(self) -> bool {
+        if !self.diverged && self.matched < self.src.len() {
+            self.out.truncate(self.matched);
+            return true;
+        }
+        self.diverged
+    }
+}
+
+impl fmt::Write for InPlace<'_> {
+    fn write_str(&mut self, part: &str) -> fmt::Result {
+        if self.diverged {
+            self.out.push_str(part);
+        } else if self.src[self.matched..].starts_with(part) {
+            self.matched += part.len();
+        } else {
+            self.out.truncate(self.matched);
+            self.out.push_str(part);
+            self.diverged = true;
+        }
+        Ok(())
+    }
+}
+
+/// A string that is converted into a case as it is formatted.  This is created by
+/// [`Casing::as_case`](crate::Casing::as_case), [`Converter::display`] and
+/// [`BorrowedConverter::display`].
+///
+/// The converted string is written directly into the formatter, so it composes with
+/// `write!`, `format_args!` and logging macros without allocating a `String`.  Width,
+/// fill and other formatting options are ignored.
+/// ```
+/// use convert_case::{Case, Casing};
+/// use core::fmt::Write;
+///
+/// let mut out = String::new();
+/// for field in ["userName", "createdAt"] {
+///     writeln!(out, "{}: {}", field.as_case(Case::Snake), field.as_case(Case::Title)).unwrap();
+/// }
+/// assert_eq!("user_name: User Name\ncreated_at: Created At\n", out);
+/// ```
+pub struct Converted<'a, T: ?Sized> {
+    s: &'a T,
+    conv: BorrowedConverter<'a>,
+}
+
+impl<T: ?Sized> Clone for Converted<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Converted<'_, T> {}
+
+impl<'a, T: AsRef<str> + ?Sized> Converted<'a, T> {
+    pub(crate) fn new(s: &'a T, case: Case<'a>) -> Self {
+        Converted {
+            s,
+            conv: BorrowedConverter::new().to_case(case),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> fmt::Display for Converted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.conv.convert_into(self.s, f)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> fmt::Debug for Converted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("\"")?;
+        fmt::Display::fmt(self, f)?;
+        f.write_str("\"")
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Casing;
+    use crate::{Casing, DEFAULT_LEXICON};
+
+    /// Converts by collecting the words and mutating them all at once.
+    fn convert_collected(conv: &Converter, s: &str) -> String {
+        let conv = conv.as_borrowed();
+        let words: Vec<&str> = boundary::split_iter(&s, conv.boundaries)
+            .with_words(conv.lexicon)
+            .collect();
+        let mut words = conv.mutate(&words);
+        conv.acronym_style
+            .apply(&mut words, conv.initialisms, conv.locale);
+        words.join(conv.delim)
+    }
+
+    #[test]
+    fn written_words_match_collected_words() {
+        let examples = [
+            "my_variable_name",
+            "XMLHttpRequest",
+            "userID json_api",
+            "ǆungla ǉubav",
+            "ΟΔΥΣΣΕΥΣ ΣΟΦΟΣ",
+            "straße ﬁsh",
+            "ᾳ ῷ",
+            "mY vArIaBlE 22",
+            "",
+        ];
+        for style in [
+            AcronymStyle::Capitalized,
+            AcronymStyle::Upper,
+            AcronymStyle::TwoLetterUpper,
+        ] {
+            for &case in Case::deterministic_cases() {
+                let conv = Converter::new().to_case(case).set_acronym_style(style);
+                for s in examples {
+                    let mut written = String::new();
+                    conv.convert_into(s, &mut written).unwrap();
+                    assert_eq!(
+                        convert_collected(&conv, s),
+                        written,
+                        "{:?} in {:?}",
+                        s,
+                        case
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn convert_into_appends() {
+        let conv = Converter::new().to_case(Case::Camel);
+        let mut out = String::from("let ");
+        conv.convert_into("max value", &mut out).unwrap();
+        assert_eq!("let maxValue", out);
+    }
+
+    #[test]
+    fn convert_into_custom_pattern() {
+        let conv = Converter::new()
+            .to_case(Case::Kebab)
+            .set_pattern(|words: &[&str]| words.iter().rev().map(|w| w.to_string()).collect());
+        let mut out = String::new();
+        conv.convert_into("a b c", &mut out).unwrap();
+        assert_eq!("c-b-a", out);
+    }
+
+    #[test]
+    fn display_matches_convert() {
+        let conv = Converter::new()
+            .to_case(Case::Pascal)
+            .set_acronym_style(AcronymStyle::Upper);
+        for s in ["http server", "user_id", ""] {
+            assert_eq!(conv.convert(s), conv.display(s).to_string());
+        }
+        assert_eq!(
+            "\"HTTPServer\"",
+            format!("{:?}", conv.display("http server"))
+        );
+    }
+
+    #[test]
+    fn convert_into_tailored() {
+        let conv = Converter::new()
+            .to_case(Case::Constant)
+            .set_locale(Locale::Turkish);
+        let mut out = String::new();
+        conv.convert_into("istanbul kart", &mut out).unwrap();
+        assert_eq!("İSTANBUL_KART", out);
+    }
+
+    #[test]
+    fn initialisms_follow_locale() {
+        let conv = Converter::new()
+            .to_case(Case::Pascal)
+            .set_acronym_style(AcronymStyle::Upper)
+            .set_locale(Locale::Turkish);
+        assert_eq!("UserİD", conv.convert("user_id"));
+    }
+
+    #[test]
+    fn borrowed_matches_owned() {
+        const TITLE: BorrowedConverter = BorrowedConverter::new()
+            .from_case(Case::Snake)
+            .to_case(Case::Title)
+            .set_acronym_style(AcronymStyle::Upper)
+            .set_initialisms(&["ID", "XML"]);
+        let conv = Converter::new()
+            .from_case(Case::Snake)
+            .to_case(Case::Title)
+            .set_acronym_style(AcronymStyle::Upper)
+            .set_initialisms(&["ID", "XML"]);
+        for s in ["user_id", "xml_fileName", "ΟΔΥΣΣΕΥΣ_ΣΟΦΟΣ", ""] {
+            assert_eq!(conv.convert(s), TITLE.convert(s));
+            assert_eq!(conv.convert(s), conv.as_borrowed().convert(s));
+            assert_eq!(conv.convert(s), TITLE.display(s).to_string());
+        }
+    }
+
+    #[test]
+    fn borrowed_defaults_match_owned() {
+        let conv = Converter::new();
+        let borrowed = BorrowedConverter::new();
+        assert_eq!(conv.boundaries, borrowed.boundaries);
+        assert!(borrowed
+            .lexicon
+            .iter()
+            .eq(conv.lexicon.iter().map(|w| &**w)));
+        assert!(borrowed
+            .initialisms
+            .iter()
+            .eq(conv.initialisms.iter().map(|w| &**w)));
+        assert_eq!(conv.delim, borrowed.delim);
+        for &case in Case::deterministic_cases() {
+            assert_eq!(
+                "Some XMLInput 2".to_case(case),
+                Converter::new().to_case(case).convert("Some XMLInput 2")
+            );
+        }
+    }
+
+    #[test]
+    fn convert_cow_borrows_unchanged() {
+        let conv = Converter::new().to_case(Case::Kebab);
+        for s in ["my-var-name", "", "x", "ǆungla-ǉubav"] {
+            assert!(matches!(conv.convert_cow(s), Cow::Borrowed(b) if b == s));
+        }
+        for s in ["my_var-name", "my-var-nameX", "My-var-name", "my--var"] {
+            let cow = conv.convert_cow(s);
+            assert!(matches!(cow, Cow::Owned(_)));
+            assert_eq!(conv.convert(s), cow);
+        }
+    }
+
+    #[test]
+    fn convert_cow_matches_convert() {
+        let examples = ["XMLHttpRequest", "user_id", "User ID", "user id", "UserId"];
+        for style in [AcronymStyle::Capitalized, AcronymStyle::Upper] {
+            for &case in Case::deterministic_cases() {
+                let conv = Converter::new().to_case(case).set_acronym_style(style);
+                for s in examples {
+                    assert_eq!(conv.convert(s), conv.convert_cow(s));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn convert_in_place_reuses_capacity() {
+        let conv = Converter::new().to_case(Case::Constant);
+        let mut s = String::with_capacity(32);
+        s.push_str("maxRetryCount");
+        let ptr = s.as_ptr();
+        assert!(conv.convert_in_place(&mut s));
+        assert_eq!("MAX_RETRY_COUNT", s);
+        assert_eq!(ptr, s.as_ptr());
+        assert!(!conv.convert_in_place(&mut s));
+
+        // Longer than the inline copy
+        let long = "maxRetryCount".repeat(20);
+        let mut s = String::with_capacity(2 * long.len());
+        s.push_str(&long);
+        let ptr = s.as_ptr();
+        assert!(conv.convert_in_place(&mut s));
+        assert_eq!(conv.convert(&long), s);
+        assert_eq!(ptr, s.as_ptr());
+    }
+
+    #[test]
+    fn convert_in_place_matches_convert() {
+        let conv = Converter::new().to_case(Case::Constant);
+        let mut s = String::from("maxRetryCount");
+        assert!(conv.convert_in_place(&mut s));
+        assert_eq!("MAX_RETRY_COUNT", s);
+        let ptr = s.as_ptr();
+        assert!(!conv.convert_in_place(&mut s));
+        assert_eq!(ptr, s.as_ptr());
+
+        // The conversion is a prefix of the original
+        let conv = Converter::new().to_case(Case::Snake);
+        let mut s = String::from("my_var_");
+        assert!(conv.convert_in_place(&mut s));
+        assert_eq!("my_var", s);
+    }
 
     #[test]
     fn snake_converter_from_case() {
@@ -376,4 +1346,92 @@ mod test {
             conv.convert("section8lesson2HTTPRequests")
         );
     }
+
+    #[test]
+    fn locale() {
+        let conv = Converter::new().to_case(Case::Upper);
+        assert_eq!("STRASSE", conv.convert("straße"));
+        let conv = conv.set_locale(Locale::German);
+        assert_eq!("STRAẞE", conv.convert("straße"));
+        let conv = conv.set_locale(Locale::Greek).to_case(Case::Title);
+        assert_eq!("Άλφα Βήτα", conv.convert("ΆΛΦΑ ΒΉΤΑ"));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_random() {
+        let conv = Converter::new().to_case(Case::Random).set_seed(99);
+        let s = "a reasonably long string of words";
+        let first = conv.convert(s);
+        assert_eq!(first, conv.convert(s));
+        assert_eq!(s, first.to_lowercase());
+
+        // The seed does not affect other patterns
+        let conv = conv.to_case(Case::Snake);
+        assert_eq!("a_reasonably_long", conv.convert("A reasonably long"));
+    }
+
+    #[test]
+    fn lexicon_words_kept_whole() {
+        let conv = Converter::new()
+            .add_lexicon(DEFAULT_LEXICON)
+            .to_case(Case::Snake);
+        assert_eq!("graphql_schema", conv.convert("GraphQLSchema"));
+        assert_eq!("ipv6_address", conv.convert("IPv6Address"));
+        assert_eq!("oauth_token", conv.convert("OAuthToken"));
+        assert_eq!("ios_app", conv.convert("iOSApp"));
+        assert_eq!("xml_graphql_schema", conv.convert("XMLGraphQLSchema"));
+        assert_eq!("my_ipv4", conv.convert("my_IPv4"));
+        assert_eq!("my_sqlite_db", conv.convert("MySQLiteDb"));
+    }
+
+    #[test]
+    fn lexicon_only_at_word_start() {
+        let conv = Converter::new()
+            .add_lexicon(DEFAULT_LEXICON)
+            .to_case(Case::Snake);
+        assert_eq!("radi_os", conv.convert("radiOS"));
+    }
+
+    #[test]
+    fn lexicon_needs_boundary_after_word() {
+        let conv = Converter::new()
+            .add_lexicon(DEFAULT_LEXICON)
+            .to_case(Case::Snake);
+        assert_eq!("o_authority", conv.convert("OAuthority"));
+        assert_eq!("git_hubber", conv.convert("GitHubber"));
+        assert_eq!("you_tuber", conv.convert("YouTuber"));
+        assert_eq!("oauth", conv.convert("OAuth"));
+        assert_eq!("oauth_2", conv.convert("OAuth_2"));
+    }
+
+    #[test]
+    fn lexicon_never_splits() {
+        let conv = Converter::new()
+            .add_lexicon(DEFAULT_LEXICON)
+            .from_case(Case::Flat)
+            .to_case(Case::Snake);
+        assert_eq!("graphqlschema", conv.convert("GraphQLSchema"));
+    }
+
+    #[test]
+    fn empty_lexicon_by_default() {
+        // Outputs from before the lexicon was added
+        let examples = [
+            ("OAuthority", "o_authority", "O Authority"),
+            ("iOSApp", "i_os_app", "I Os App"),
+            ("GraphQLSchema", "graph_ql_schema", "Graph Ql Schema"),
+            ("IPv6Address", "i_pv_6_address", "I Pv 6 Address"),
+            ("MySQLiteDb", "my_sq_lite_db", "My Sq Lite Db"),
+            ("macOSVersion", "mac_os_version", "Mac Os Version"),
+            ("YouTuber", "you_tuber", "You Tuber"),
+            ("XMLHttpRequest", "xml_http_request", "Xml Http Request"),
+            ("getIPv4", "get_i_pv_4", "Get I Pv 4"),
+        ];
+        for (s, snake, title) in examples {
+            assert_eq!(snake, s.to_case(Case::Snake));
+            assert_eq!(title, s.to_case(Case::Title));
+            assert_eq!(snake, Converter::new().to_case(Case::Snake).convert(s));
+        }
+    }
 }
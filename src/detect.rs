@@ -0,0 +1,433 @@
+use crate::boundary::{self, Boundary};
+use crate::pattern::{self, Pattern};
+use crate::Case;
+
+use alloc::vec::Vec;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A case that an identifier could be in, paired with a confidence score.
+///
+/// The confidence of every candidate in a [`Detection`] sums to `1.0`.  A case
+/// gains confidence for each word boundary in the identifier that is explained
+/// by the boundaries of that case, so `"myVar"` is more confidently camel case than
+/// flat case, even though both would reproduce it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate<'a> {
+    /// The detected case.
+    pub case: Case<'a>,
+    /// How likely it is that the identifier is in this case, between `0.0` and `1.0`.
+    pub confidence: f32,
+}
+
+/// The cases an identifier could be in, ranked by confidence.
+///
+/// Candidates are chosen from [`Case::deterministic_cases`], along with the cases of a
+/// [`CaseRegistry`](crate::CaseRegistry) when detecting with [`CaseRegistry::detect`](crate::CaseRegistry::detect).  A case is a candidate
+/// when converting the identifier into that case would leave it unchanged, which is the
+/// same definition used by [`Casing::is_case`](crate::Casing::is_case).  Unlike `is_case`,
+/// detection inspects the words rather than running a conversion for each case.  The
+/// identifier is split once on the default boundaries, and again for each case whose own
+/// boundaries, like the `"."` of [`Case::Dot`], are not among the defaults.
+/// ```
+/// use convert_case::{Case, Casing};
+///
+/// let detection = "my_variable_name".detect_case();
+/// assert_eq!(Some(Case::Snake), detection.best());
+/// assert!(!detection.is_ambiguous());
+///
+/// // A single lowercase word could be in many cases
+/// let detection = "foo".detect_case();
+/// assert!(detection.is_ambiguous());
+/// assert_eq!(
+///     vec![
+///         Case::Snake,
+///         Case::Kebab,
+///         Case::Flat,
+///         Case::Camel,
+///         Case::Lower,
+///         Case::Dot,
+///         Case::Path,
+///     ],
+///     detection.cases().collect::<Vec<_>>(),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Detection<'a> {
+    candidates: Vec<Candidate<'a>>,
+}
+
+impl<'a> Detection<'a> {
+    /// The candidates ordered from most to least confident.  Candidates with
+    /// equal confidence are in the order of [`Case::deterministic_cases`], followed
+    /// by registered cases in the order they were registered.
+    pub fn candidates(&self) -> &[Candidate<'a>] {
+        &self.candidates
+    }
+
+    /// The candidate cases ordered from most to least confident.
+    pub fn cases(&self) -> impl Iterator<Item = Case<'a>> + '_ {
+        self.candidates.iter().map(|c| c.case)
+    }
+
+    /// The most confident case, or `None` if no case matches the identifier.
+    /// When the detection [is ambiguous](Detection::is_ambiguous), this is the
+    /// first of the equally confident cases.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(Some(Case::Camel), "myVar".detect_case().best());
+    /// assert_eq!(None, "my_Var".detect_case().best());
+    /// ```
+    pub fn best(&self) -> Option<Case<'a>> {
+        self.candidates.first().map(|c| c.case)
+    }
+
+    /// Returns the confidence of the given case, or `0.0` if it is not a candidate.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let detection = "VARIABLE".detect_case();
+    /// assert_eq!(0.2, detection.confidence(Case::Constant));
+    /// assert_eq!(0.0, detection.confidence(Case::Snake));
+    /// ```
+    pub fn confidence(&self, case: Case) -> f32 {
+        self.candidates
+            .iter()
+            .find(|c| c.case == case)
+            .map_or(0.0, |c| c.confidence)
+    }
+
+    /// Whether more than one case shares the highest confidence.
+    pub fn is_ambiguous(&self) -> bool {
+        match self.candidates.as_slice() {
+            [first, second, ..] => first.confidence == second.confidence,
+            _ => false,
+        }
+    }
+
+    /// Whether no case matches the identifier.
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+}
+
+/// How a single word could have been mutated by a pattern.
+#[derive(Clone, Copy)]
+struct Shape {
+    lower: bool,
+    upper: bool,
+    capital: bool,
+    toggle: bool,
+}
+
+impl Shape {
+    fn of(word: &str) -> Self {
+        let mut graphemes = word.graphemes(true);
+        let first = graphemes.next().unwrap_or_default();
+        let rest = graphemes.as_str();
+
+        Shape {
+            lower: is_lowercase(word),
+            upper: is_uppercase(word),
+            capital: is_uppercase(first) && is_lowercase(rest),
+            toggle: is_lowercase(first) && is_uppercase(rest),
+        }
+    }
+}
+
+fn is_lowercase(s: &str) -> bool {
+    s.chars().flat_map(char::to_lowercase).eq(s.chars())
+}
+
+fn is_uppercase(s: &str) -> bool {
+    s.chars().flat_map(char::to_uppercase).eq(s.chars())
+}
+
+/// Letters alternate between lowercase and uppercase across the whole string,
+/// starting with lowercase, as produced by [`pattern::alternating`](crate::pattern::alternating).
+fn is_alternating(s: &str) -> bool {
+    let mut upper = false;
+    for c in s.chars().filter(|c| c.is_uppercase() || c.is_lowercase()) {
+        let same = if upper {
+            c.to_uppercase().eq([c])
+        } else {
+            c.to_lowercase().eq([c])
+        };
+        if !same {
+            return false;
+        }
+        upper = !upper;
+    }
+    true
+}
+
+/// Patterns that mutate words differently each time, which are never detected.
+const RANDOM_PATTERNS: &[&dyn Pattern] = &[
+    #[cfg(feature = "random")]
+    &pattern::random,
+    #[cfg(feature = "random")]
+    &pattern::pseudo_random,
+];
+
+fn matches_pattern(case: Case, words: &[&str], shapes: &[Shape], s: &str) -> bool {
+    use Case::*;
+    let all = |f: fn(&Shape) -> bool| shapes.iter().all(f);
+    let (first, rest) = match shapes.split_first() {
+        Some(split) => split,
+        None => return true,
+    };
+    match case {
+        Snake | Kebab | Flat | Lower | Dot | Path => all(|w| w.lower),
+        Constant | UpperSnake | Cobol | UpperKebab | UpperFlat | Upper | UpperDot => {
+            all(|w| w.upper)
+        }
+        Ada | Train | Pascal | UpperCamel | Title | Namespace => all(|w| w.capital),
+        Camel => first.lower && rest.iter().all(|w| w.capital),
+        Sentence => first.capital && rest.iter().all(|w| w.lower),
+        Toggle => all(|w| w.toggle),
+        Alternating => is_alternating(s),
+        Custom { pattern, .. } => {
+            !RANDOM_PATTERNS.iter().any(|&p| pattern::same(p, pattern))
+                && pattern.mutate(words) == words
+        }
+        #[cfg(feature = "random")]
+        Random | PseudoRandom => false,
+    }
+}
+
+/// The boundaries that an identifier in `case` is split on: those of the case that are
+/// not [defaults](Boundary::defaults), followed by the defaults.  Toggle and alternating
+/// case change the case of letters within a word, so they are not split where the case
+/// changes.  Returns `None` when these are just the defaults.
+pub(crate) fn case_boundaries(case: &Case) -> Option<Vec<Boundary>> {
+    let defaults = Boundary::defaults();
+    let mut boundaries: Vec<Boundary> = case
+        .boundaries()
+        .iter()
+        .filter(|b| !defaults.contains(b))
+        .cloned()
+        .collect();
+    match case {
+        Case::Toggle | Case::Alternating => boundaries.extend(
+            defaults
+                .into_iter()
+                .filter(|b| *b != Boundary::LOWER_UPPER && *b != Boundary::ACRONYM),
+        ),
+        _ if boundaries.is_empty() => return None,
+        _ => boundaries.extend(defaults),
+    }
+    Some(boundaries)
+}
+
+/// An identifier split into words, with the delimiter that joins them.
+struct Layout<'s> {
+    words: Vec<&'s str>,
+    delim: Option<&'s str>,
+    shapes: Vec<Shape>,
+}
+
+impl<'s> Layout<'s> {
+    /// Splits `s` on the given boundaries.  Returns `None` when `s` is empty, or when
+    /// it is not the words joined by a single delimiter.
+    fn of(s: &'s str, boundaries: &[Boundary]) -> Option<Self> {
+        let words: Vec<&str> = boundary::split_iter(s, boundaries).collect();
+        let (first, last) = (*words.first()?, *words.last()?);
+
+        // Words are slices of `s`, so whatever lies between them was consumed as a delimiter.
+        let offset = |word: &str| word.as_ptr() as usize - s.as_ptr() as usize;
+        if offset(first) != 0 || offset(last) + last.len() != s.len() {
+            return None;
+        }
+        let mut gaps = words
+            .windows(2)
+            .map(|pair| &s[offset(pair[0]) + pair[0].len()..offset(pair[1])]);
+        let delim = gaps.next();
+        if gaps.any(|gap| Some(gap) != delim) {
+            return None;
+        }
+
+        let shapes = words.iter().map(|w| Shape::of(w)).collect();
+        Some(Layout {
+            words,
+            delim,
+            shapes,
+        })
+    }
+}
+
+pub(crate) fn detect(s: &str) -> Detection<'static> {
+    detect_among(s, Case::deterministic_cases().iter().copied())
+}
+
+/// Detects which of the given cases `s` could be in.
+pub(crate) fn detect_among<'a, I>(s: &str, cases: I) -> Detection<'a>
+where
+    I: IntoIterator<Item = Case<'a>>,
+{
+    let defaults = Layout::of(s, &Boundary::defaults());
+
+    let mut weights = Vec::new();
+    for case in cases {
+        let own;
+        let layout = match case_boundaries(&case) {
+            Some(boundaries) => {
+                own = Layout::of(s, &boundaries);
+                own.as_ref()
+            }
+            None => defaults.as_ref(),
+        };
+        let Some(Layout {
+            words,
+            delim,
+            shapes,
+        }) = layout
+        else {
+            continue;
+        };
+
+        let delim_matches = delim.is_none_or(|d| d == case.delim());
+        if delim_matches && matches_pattern(case, words, shapes, s) {
+            let explained = if case.boundaries().is_empty() {
+                0
+            } else {
+                words.len() - 1
+            };
+            weights.push((case, 1 + explained));
+        }
+    }
+
+    let total: usize = weights.iter().map(|(_, w)| w).sum();
+    let mut candidates: Vec<Candidate<'a>> = weights
+        .into_iter()
+        .map(|(case, weight)| Candidate {
+            case,
+            confidence: weight as f32 / total as f32,
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    Detection { candidates }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CaseFamily, Casing};
+
+    #[test]
+    fn agrees_with_is_case() {
+        let examples = [
+            "my_variable_name",
+            "MY_VARIABLE_NAME",
+            "My_Variable_Name",
+            "my-variable-name",
+            "MY-VARIABLE-NAME",
+            "My-Variable-Name",
+            "myvariablename",
+            "MYVARIABLENAME",
+            "MyVariableName",
+            "myVariableName",
+            "my variable name",
+            "MY VARIABLE NAME",
+            "My Variable Name",
+            "My variable name",
+            "mY vArIaBlE nAmE",
+            "mY vARIABLE nAME",
+            "myVariable22Name",
+            "My Variable 22 Name",
+            "variable",
+            "Variable",
+            "VARIABLE",
+            "a1",
+            "foo.bar",
+            "_leading",
+            "trailing-",
+            "double__underscore",
+            "mixed_delim-iters",
+            "XMLHttpRequest",
+            "my.variable.name",
+            "MY.VARIABLE.NAME",
+            "my/variable/name",
+            "My::Variable::Name",
+            "My::variable",
+            "ὀδυσσεύς",
+            "Перспектива 24",
+        ];
+
+        for s in examples {
+            let detected = detect(s);
+            for &case in Case::deterministic_cases() {
+                assert_eq!(
+                    s.is_case(case),
+                    detected.cases().any(|c| c == case),
+                    "{:?} in {:?}",
+                    s,
+                    case
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn own_output_is_best() {
+        for s in ["My Variable Name", "XML Http Request 2"] {
+            for &case in Case::deterministic_cases() {
+                let converted = s.to_case(case);
+                assert!(converted.is_case(case), "{:?} in {:?}", converted, case);
+                let detection = detect(&converted);
+                // Flat cases have no delimiter, so their output is a single word that
+                // other cases produce too
+                if case.family() == CaseFamily::Flat {
+                    assert!(detection.confidence(case) > 0.0, "{:?}", converted);
+                } else {
+                    assert_eq!(Some(case), detection.best(), "{:?}", converted);
+                    assert!(!detection.is_ambiguous(), "{:?}", converted);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn delimiters_outside_defaults() {
+        let detection = detect("My::Var::Name");
+        assert_eq!(Some(Case::Namespace), detection.best());
+        assert_eq!(1.0, detection.confidence(Case::Namespace));
+
+        let detection = detect("my.var.name");
+        assert_eq!(Some(Case::Dot), detection.best());
+        assert!(!detection.is_ambiguous());
+        assert!(detection.confidence(Case::Snake) > 0.0);
+    }
+
+    #[test]
+    fn empty_string() {
+        assert!(detect("").is_empty());
+    }
+
+    #[test]
+    fn unambiguous() {
+        let detection = detect("My-Variable-Name");
+        assert_eq!(Some(Case::Train), detection.best());
+        assert_eq!(1.0, detection.confidence(Case::Train));
+        assert!(!detection.is_ambiguous());
+    }
+
+    #[test]
+    fn explained_boundaries_rank_higher() {
+        let detection = detect("a1");
+        assert_eq!(
+            vec![Case::Camel, Case::Flat],
+            detection.cases().collect::<Vec<_>>()
+        );
+        assert!(!detection.is_ambiguous());
+    }
+
+    #[test]
+    fn confidences_sum_to_one() {
+        for s in ["foo", "Foo", "FOO", "a1", "foo_bar"] {
+            let sum: f32 = detect(s).candidates().iter().map(|c| c.confidence).sum();
+            assert!((sum - 1.0).abs() < 1e-6);
+        }
+    }
+}
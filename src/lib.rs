@@ -54,10 +54,11 @@
 //! assert_eq!("weird_var_name", "__weird--var _name-".to_case(Case::Snake));
 //! ```
 //!
-//! It also works non-ascii characters.  However, no inferences on the language itself is made.
+//! It also works non-ascii characters.  By default, no inferences on the language itself is made.
 //! For instance, the digraph `ij` in Dutch will not be capitalized, because it is represented
-//! as two distinct Unicode characters.  However, `æ` would be capitalized.  Accuracy with unicode
-//! characters is done using the `unicode-segmentation` crate, the sole dependency of this crate.
+//! as two distinct Unicode characters, unless the [`Locale::Dutch`] locale is used.  However, `æ`
+//! would be capitalized.  Accuracy with unicode characters is done using the `unicode-segmentation`
+//! crate, the sole dependency of this crate.
 //! ```
 //! # use convert_case::{Case, Casing};
 //! assert_eq!("granat-äpfel", "GranatÄpfel".to_case(Case::Kebab));
@@ -87,6 +88,14 @@
 //! assert!(!"UPPER_CASE_VAR".is_case(Case::Snake));
 //! ```
 //!
+//! Or detect the case without knowing it ahead of time.  When a string could be in
+//! several cases, the [`Detection`] reports each candidate with a confidence.
+//! ```
+//! # use convert_case::{Case, Casing};
+//! assert_eq!(Some(Case::Pascal), "CssClassName".detect_case().best());
+//! assert!("css".detect_case().is_ambiguous());
+//! ```
+//!
 //! # Note on Accuracy
 //!
 //! The `Casing` methods `from_case` and `to_case` do not fail.  Conversion to a case will always
@@ -164,14 +173,13 @@
 //! symbol and the second is lowercase, you can instantiate a boundary directly.
 //!
 //! ```
-//! # use convert_case::{Boundary, Case, Casing};
+//! # use convert_case::{Boundary, Case, Casing, Condition};
 //! let at_then_letter = Boundary {
-//!     name: "AtLetter",
-//!     condition: |s, _| {
-//!         s.get(0).map(|c| *c == "@") == Some(true)
+//!     name: "AtLetter".into(),
+//!     condition: Condition::Fn(|s| {
+//!         s.first().map(|c| *c == "@") == Some(true)
 //!             && s.get(1).map(|c| *c == c.to_lowercase()) == Some(true)
-//!     },
-//!     arg: None,
+//!     }),
 //!     start: 1,
 //!     len: 0,
 //! };
@@ -183,6 +191,25 @@
 //! )
 //! ```
 //!
+//! Boundaries can also hold runtime data, such as delimiters read from a configuration file,
+//! by implementing [`BoundaryCondition`] or capturing the data in a closure.
+//! ```
+//! # use convert_case::{Boundary, Case, Casing};
+//! let delims: Vec<String> = vec![".".into(), "/".into()];
+//! let config_delim = Boundary::from_condition(
+//!     "ConfigDelim",
+//!     move |s: &[&str]| s.first().map_or(false, |g| delims.iter().any(|d| d == g)),
+//!     0,
+//!     1,
+//! );
+//! assert_eq!(
+//!     "Src Main Rs",
+//!     "src/main.rs"
+//!         .with_boundaries(&[config_delim])
+//!         .to_case(Case::Title)
+//! )
+//! ```
+//!
 //! To learn more about building a boundary from scratch, read the [`Boundary`] struct.
 //!
 //! # Custom Case
@@ -198,7 +225,7 @@
 //!
 //! let dot_case = Case::Custom {
 //!     boundaries: &[Boundary::from_delim(".")],
-//!     pattern: pattern::lowercase,
+//!     pattern: &pattern::lowercase,
 //!     delim: ".",
 //! };
 //!
@@ -212,7 +239,7 @@
 //! # use convert_case::{Case, Casing, pattern, Boundary};
 //! # let dot_case = Case::Custom {
 //! #     boundaries: &[Boundary::from_delim(".")],
-//! #     pattern: pattern::lowercase,
+//! #     pattern: &pattern::lowercase,
 //! #     delim: ".",
 //! # };
 //! assert_eq!(
@@ -220,6 +247,10 @@
 //!     "dot.case.var".from_case(dot_case).to_case(Case::Camel)
 //! )
 //! ```
+//! A custom case borrows its boundaries and delimiter.  To define a case at runtime, such as
+//! from a configuration file, build a [`CaseSpec`] instead, which owns its parts and is
+//! borrowed as a custom case with [`as_case`](CaseSpec::as_case).  Specs can be registered by
+//! name in a [`CaseRegistry`], which parses and detects them alongside the built-in cases.
 //!
 //! # Converter Struct
 //!
@@ -245,6 +276,15 @@
 //! ```
 //! For more details on how strings are converted, see the docs for [`Converter`].
 //!
+//! A [`BorrowedConverter`] borrows its boundaries and delimiter instead of owning them, and
+//! is built with `const fn`s, so it can be declared once as a `static`.
+//! ```
+//! use convert_case::{BorrowedConverter, Case};
+//!
+//! static SNAKE: BorrowedConverter = BorrowedConverter::new().to_case(Case::Snake);
+//! assert_eq!("my_special_case", SNAKE.convert("My Special Case"));
+//! ```
+//!
 //! # Random Feature
 //!
 //! This feature adds two additional cases: [`Case::Random`] and [`Case::PseudoRandom`].
@@ -253,22 +293,52 @@
 //! You can enable this feature by including the following in your `Cargo.toml`.
 //! ```{toml}
 //! [dependencies]
-//! convert_case = { version = "^0.8.0", features = ["random"] }
+//! convert_case = { version = "^0.9.0", features = ["random"] }
+//! ```
+//!
+//! # Serde Feature
+//!
+//! This feature implements `Serialize` and `Deserialize` for [`Case`], [`Boundary`],
+//! [`TitleCase`](pattern::TitleCase), [`Converter`], [`CaseSpec`] and [`CaseRegistry`] using the [`serde`](https://docs.rs/serde) crate, so conversion settings
+//! can be stored in configuration files.  Cases are written by name, built-in boundaries
+//! by their `name` field, and converters and case specs as a map of their fields with the pattern written as
+//! the name of a function in the [`pattern`] module, or as a title case pattern.  Custom cases, and boundaries and patterns
+//! defined by custom functions, cannot be serialized and return an error.
+//! ```{toml}
+//! [dependencies]
+//! convert_case = { version = "^0.9.0", features = ["serde"] }
 //! ```
 
 #![cfg_attr(not(test), no_std)]
 extern crate alloc;
 
+use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
+use core::fmt;
 
+mod acronym;
 mod boundary;
 mod case;
 mod converter;
+mod detect;
+mod locale;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod spec;
 
+pub mod naming;
 pub mod pattern;
-pub use boundary::{split, Boundary};
-pub use case::Case;
-pub use converter::Converter;
+pub mod sanitize;
+pub use acronym::{AcronymStyle, DEFAULT_INITIALISMS};
+pub use boundary::{
+    split, split_iter, split_spans, Boundary, BoundaryCondition, Condition, Span, SplitIter,
+    SplitSpans, DEFAULT_LEXICON, LOOKAHEAD,
+};
+pub use case::{Case, CaseFamily, ParseCaseError};
+pub use converter::{BorrowedConverter, Converted, Converter};
+pub use detect::{Candidate, Detection};
+pub use locale::Locale;
+pub use spec::{CaseRegistry, CaseSpec};
 
 /// Describes items that can be converted into a case.  This trait is used
 /// in conjunction with the [`StateConverter`] struct which is returned from a couple
@@ -287,6 +357,40 @@ pub trait Casing<T: AsRef<str>> {
     /// ```
     fn to_case(&self, case: Case) -> String;
 
+    /// Convert the string into the given case and write it into `out`, without
+    /// allocating a new `String`.  See [`Converter::convert_into`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use core::fmt::Write;
+    ///
+    /// let mut out = String::from("fn ");
+    /// "Parse Header".to_case_into(Case::Snake, &mut out).unwrap();
+    /// out.push_str("()");
+    /// assert_eq!("fn parse_header()", out);
+    /// ```
+    fn to_case_into<W: fmt::Write>(&self, case: Case, out: &mut W) -> fmt::Result;
+
+    /// Returns a wrapper that converts the string into the given case when it is
+    /// formatted, without creating an intermediate `String`.  See [`Converted`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let name = "parseHeader";
+    /// assert_eq!("fn parse_header()", format!("fn {}()", name.as_case(Case::Snake)));
+    /// ```
+    fn as_case<'a>(&'a self, case: Case<'a>) -> Converted<'a, T>;
+
+    /// Converts `self` into the given case, borrowing it when it is already in that
+    /// case.  See [`Converter::convert_cow`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use std::borrow::Cow;
+    ///
+    /// assert!(matches!("user_name".to_case_cow(Case::Snake), Cow::Borrowed(_)));
+    /// assert_eq!("user_name", "userName".to_case_cow(Case::Snake));
+    /// ```
+    fn to_case_cow(&self, case: Case) -> Cow<'_, str>;
+
     /// Start the case conversion by storing the boundaries associated with the given case.
     /// ```
     /// use convert_case::{Case, Casing};
@@ -299,7 +403,7 @@ pub trait Casing<T: AsRef<str>> {
     /// );
     /// ```
     #[allow(clippy::wrong_self_convention)]
-    fn from_case(&self, case: Case) -> StateConverter<T>;
+    fn from_case(&self, case: Case) -> StateConverter<'_, T>;
 
     /// Creates a `StateConverter` struct initialized with the boundaries
     /// provided.
@@ -313,7 +417,7 @@ pub trait Casing<T: AsRef<str>> {
     ///         .to_case(Case::Snake)
     /// );
     /// ```
-    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<T>;
+    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T>;
 
     /// Creates a `StateConverter` struct initialized without the boundaries
     /// provided.
@@ -327,20 +431,33 @@ pub trait Casing<T: AsRef<str>> {
     ///         .to_case(Case::Snake)
     /// );
     /// ```
-    fn without_boundaries(&self, bs: &[Boundary]) -> StateConverter<T>;
+    fn without_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T>;
 
     /// Determines if `self` is of the given case.  This is done simply by applying
-    /// the conversion and seeing if the result is the same.
+    /// the conversion and seeing if the result is the same.  Words are split on the
+    /// boundaries of the case as well as the defaults, so that delimiters like the
+    /// `"::"` of [`Case::Namespace`] are recognized.
     /// ```
     /// use convert_case::{Case, Casing};
     ///
     /// assert!( "kebab-case-string".is_case(Case::Kebab));
     /// assert!( "Train-Case-String".is_case(Case::Train));
+    /// assert!( "My::Case::String".is_case(Case::Namespace));
     ///
     /// assert!(!"kebab-case-string".is_case(Case::Snake));
     /// assert!(!"kebab-case-string".is_case(Case::Train));
     /// ```
     fn is_case(&self, case: Case) -> bool;
+
+    /// Detects which cases `self` could be in, ranked by confidence.  See [`Detection`]
+    /// for how candidates are chosen.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(Some(Case::Kebab), "kebab-case-string".detect_case().best());
+    /// assert!("string".detect_case().is_ambiguous());
+    /// ```
+    fn detect_case(&self) -> Detection<'static>;
 }
 
 impl<T: AsRef<str>> Casing<T> for T
@@ -348,25 +465,79 @@ where
     T: ToString,
 {
     fn to_case(&self, case: Case) -> String {
-        StateConverter::new(self).to_case(case)
+        BorrowedConverter::new().to_case(case).convert(self)
+    }
+
+    fn to_case_into<W: fmt::Write>(&self, case: Case, out: &mut W) -> fmt::Result {
+        BorrowedConverter::new()
+            .to_case(case)
+            .convert_into(self, out)
+    }
+
+    fn as_case<'a>(&'a self, case: Case<'a>) -> Converted<'a, T> {
+        Converted::new(self, case)
+    }
+
+    fn to_case_cow(&self, case: Case) -> Cow<'_, str> {
+        BorrowedConverter::new().to_case(case).convert_cow(self)
     }
 
-    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<T> {
+    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T> {
         StateConverter::new(self).with_boundaries(bs)
     }
 
-    fn without_boundaries(&self, bs: &[Boundary]) -> StateConverter<T> {
+    fn without_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T> {
         StateConverter::new(self).without_boundaries(bs)
     }
 
-    fn from_case(&self, case: Case) -> StateConverter<T> {
+    fn from_case(&self, case: Case) -> StateConverter<'_, T> {
         StateConverter::new(self).from_case(case)
     }
 
     fn is_case(&self, case: Case) -> bool {
-        // TODO: rewrite
-        //&self.to_case(case) == self
-        self.to_case(case) == self.to_string()
+        let boundaries = detect::case_boundaries(&case);
+        let conv = BorrowedConverter::new().to_case(case);
+        let conv = match &boundaries {
+            Some(boundaries) => conv.set_boundaries(boundaries),
+            None => conv,
+        };
+        matches!(conv.convert_cow(self), Cow::Borrowed(_))
+    }
+
+    fn detect_case(&self) -> Detection<'static> {
+        Case::detect(self)
+    }
+}
+
+/// Describes strings that can be converted into a case in place.
+///
+/// This is implemented for `String`, so owned values can be normalized without replacing
+/// them.  For finer control, see [`Converter::convert_in_place`].
+/// ```
+/// use convert_case::{Case, MakeCase};
+///
+/// let mut fields = vec![String::from("firstName"), String::from("last_name")];
+/// let mut changed = 0;
+/// for field in &mut fields {
+///     if field.make_case(Case::Snake) {
+///         changed += 1;
+///     }
+/// }
+/// assert_eq!(1, changed);
+/// assert_eq!(vec!["first_name", "last_name"], fields);
+/// ```
+pub trait MakeCase {
+    /// Converts `self` into the given case in place, returning whether it changed.
+    /// A string already in the case is not copied, and the existing allocation is
+    /// reused when the converted string fits.
+    fn make_case(&mut self, case: Case) -> bool;
+}
+
+impl MakeCase for String {
+    fn make_case(&mut self, case: Case) -> bool {
+        BorrowedConverter::new()
+            .to_case(case)
+            .convert_in_place(self)
     }
 }
 
@@ -387,7 +558,7 @@ pub struct StateConverter<'a, T: AsRef<str>> {
 }
 
 impl<'a, T: AsRef<str>> StateConverter<'a, T> {
-    /// Only called by Casing function to_case()
+    /// Only called by the Casing functions that change the boundaries
     fn new(s: &'a T) -> Self {
         Self {
             s,
@@ -454,6 +625,95 @@ impl<'a, T: AsRef<str>> StateConverter<'a, T> {
         }
     }
 
+    /// Overwrites the lexicon of words that are kept whole with those provided.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "dynamodb_stream",
+    ///     "DynamoDBStream".from_case(Case::Pascal).with_lexicon(&["DynamoDB"]).to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn with_lexicon<W: AsRef<str>>(self, words: &[W]) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.set_lexicon(words),
+        }
+    }
+
+    /// Adds to the lexicon of words that are kept whole.
+    /// ```
+    /// use convert_case::{Case, Casing, DEFAULT_LEXICON};
+    ///
+    /// assert_eq!(
+    ///     "graphql_over_dynamodb",
+    ///     "GraphQLOverDynamoDB"
+    ///         .from_case(Case::Pascal)
+    ///         .add_lexicon(DEFAULT_LEXICON)
+    ///         .add_lexicon(&["DynamoDB"])
+    ///         .to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn add_lexicon<W: AsRef<str>>(self, words: &[W]) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.add_lexicon(words),
+        }
+    }
+
+    /// Removes all words from the lexicon, so words are split wherever a boundary is present.
+    /// ```
+    /// use convert_case::{Case, Casing, DEFAULT_LEXICON};
+    ///
+    /// assert_eq!(
+    ///     "graph_ql_schema",
+    ///     "GraphQLSchema"
+    ///         .from_case(Case::Pascal)
+    ///         .add_lexicon(DEFAULT_LEXICON)
+    ///         .without_lexicon()
+    ///         .to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn without_lexicon(self) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.remove_lexicon(),
+        }
+    }
+
+    /// Follows the case mappings of the given language.  See [`Locale`].
+    /// ```
+    /// use convert_case::{Case, Casing, Locale};
+    ///
+    /// assert_eq!(
+    ///     "IJzer Staal",
+    ///     "ijzer_staal".from_case(Case::Snake).with_locale(Locale::Dutch).to_case(Case::Title)
+    /// );
+    /// ```
+    pub fn with_locale(self, locale: Locale) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.set_locale(locale),
+        }
+    }
+
+    /// Seeds the random number generator used by [`Case::Random`] and [`Case::PseudoRandom`],
+    /// so the result is the same every time.  Only available with the "random" feature.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let mocking = "mocking spongebob".from_case(Case::Lower).with_seed(7);
+    /// let again = "mocking spongebob".from_case(Case::Lower).with_seed(7);
+    /// assert_eq!(mocking.to_case(Case::PseudoRandom), again.to_case(Case::PseudoRandom));
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.set_seed(seed),
+        }
+    }
+
     /// Consumes the `StateConverter` and returns the converted string.
     /// ```
     /// use convert_case::{Boundary, Case, Casing};
@@ -464,7 +724,35 @@ impl<'a, T: AsRef<str>> StateConverter<'a, T> {
     /// );
     /// ```
     pub fn to_case(self, case: Case) -> String {
-        self.conv.to_case(case).convert(self.s)
+        self.conv.as_borrowed().to_case(case).convert(self.s)
+    }
+
+    /// Consumes the `StateConverter` and writes the converted string into `out`.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let mut out = String::new();
+    /// "ice_cream".from_case(Case::Snake).to_case_into(Case::Title, &mut out).unwrap();
+    /// assert_eq!("Ice Cream", out);
+    /// ```
+    pub fn to_case_into<W: fmt::Write>(self, case: Case, out: &mut W) -> fmt::Result {
+        self.conv
+            .as_borrowed()
+            .to_case(case)
+            .convert_into(self.s, out)
+    }
+
+    /// Consumes the `StateConverter` and returns the converted string, borrowing
+    /// the original when it is unchanged.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use std::borrow::Cow;
+    ///
+    /// let words = "Ice Cream".from_case(Case::Title).to_case_cow(Case::Lower);
+    /// assert_eq!(Cow::<str>::Owned("ice cream".to_string()), words);
+    /// ```
+    pub fn to_case_cow(self, case: Case) -> Cow<'a, str> {
+        self.conv.as_borrowed().to_case(case).convert_cow(self.s)
     }
 }
 
@@ -475,11 +763,11 @@ mod test {
     use alloc::vec;
     use alloc::vec::Vec;
 
-    fn possible_cases(s: &str) -> Vec<Case> {
+    fn possible_cases(s: &str) -> Vec<Case<'_>> {
         Case::deterministic_cases()
             .iter()
             .filter(|&case| s.from_case(*case).to_case(*case) == s)
-            .map(|c| *c)
+            .copied()
             .collect()
     }
 
@@ -611,10 +899,7 @@ mod test {
 
     #[test]
     fn empty_string() {
-        for (case_a, case_b) in Case::all_cases()
-            .into_iter()
-            .zip(Case::all_cases().into_iter())
-        {
+        for (case_a, case_b) in Case::all_cases().iter().zip(Case::all_cases()) {
             assert_eq!("", "".from_case(*case_a).to_case(*case_b));
         }
     }
@@ -627,6 +912,19 @@ mod test {
         )
     }
 
+    #[test]
+    fn make_case_owned_string() {
+        let mut s = String::from("TestVariable");
+        assert!(s.make_case(Case::Snake));
+        assert_eq!("test_variable", s);
+        assert!(!s.make_case(Case::Snake));
+        assert!(s.make_case(Case::Kebab));
+        assert_eq!("test-variable", s);
+
+        let mut empty = String::new();
+        assert!(!empty.make_case(Case::Title));
+    }
+
     #[test]
     fn default_all_boundaries() {
         assert_eq!(
@@ -702,18 +1000,20 @@ mod test {
 
     #[test]
     fn detect_many_cases() {
-        let lower_cases_vec = possible_cases(&"asef");
-        let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
+        let lower_cases_vec = possible_cases("asef");
+        let lower_cases_set = HashSet::from_iter(lower_cases_vec);
         let mut actual = HashSet::new();
         actual.insert(Case::Lower);
         actual.insert(Case::Camel);
         actual.insert(Case::Snake);
         actual.insert(Case::Kebab);
         actual.insert(Case::Flat);
+        actual.insert(Case::Dot);
+        actual.insert(Case::Path);
         assert_eq!(lower_cases_set, actual);
 
-        let lower_cases_vec = possible_cases(&"asefCase");
-        let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
+        let lower_cases_vec = possible_cases("asefCase");
+        let lower_cases_set = HashSet::from_iter(lower_cases_vec);
         let mut actual = HashSet::new();
         actual.insert(Case::Camel);
         assert_eq!(lower_cases_set, actual);
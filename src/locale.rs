@@ -0,0 +1,361 @@
+use alloc::string::String;
+use core::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Language-specific rules for changing the case of letters.
+///
+/// The default mappings are those of [`str::to_lowercase`] and [`str::to_uppercase`],
+/// which are the same for every language.  Some languages tailor those mappings, and a
+/// [`Converter`](crate::Converter) can be told to follow them with
+/// [`set_locale`](crate::Converter::set_locale).  The tailorings apply to the
+/// [`lowercase`](crate::pattern::lowercase), [`uppercase`](crate::pattern::uppercase),
+/// [`capital`](crate::pattern::capital), [`camel`](crate::pattern::camel),
+/// [`sentence`](crate::pattern::sentence) and [`toggle`](crate::pattern::toggle) patterns.
+/// ```
+/// use convert_case::{Case, Converter, Locale};
+///
+/// let conv = Converter::new().to_case(Case::Constant);
+/// assert_eq!("ISTANBUL_KART", conv.convert("istanbul kart"));
+///
+/// let conv = conv.set_locale(Locale::Turkish);
+/// assert_eq!("İSTANBUL_KART", conv.convert("istanbul kart"));
+///
+/// let conv = Converter::new().to_case(Case::Title).set_locale(Locale::Dutch);
+/// assert_eq!("IJsselmeer Dijk", conv.convert("ijsselmeer dijk"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// The language-independent mappings of the Unicode standard.
+    #[default]
+    Root,
+    /// Turkish, where `i` and `ı` are distinct letters whose uppercase forms are `İ`
+    /// and `I`.
+    Turkish,
+    /// Azerbaijani, which maps `i` and `ı` the same as [Turkish](Locale::Turkish).
+    Azeri,
+    /// Dutch, where the digraph `ij` at the start of a word is capitalized as `IJ`.
+    Dutch,
+    /// German, where `ß` is uppercased to the capital `ẞ` rather than `SS`.
+    German,
+    /// Greek, where accents are dropped from uppercase letters, such as `Ά` in
+    /// `ΆΘΗΝΑ` becoming `ΑΘΗΝΑ`.  A capitalized word keeps the accent on its
+    /// first letter.  Only the monotonic tonos and dialytika tonos are dropped.
+    Greek,
+    /// Lithuanian, where the dot above `i` and `j` is kept when an accent is
+    /// added above them, and removed when they are uppercased.
+    Lithuanian,
+}
+
+impl Locale {
+    /// Makes every letter lowercase.
+    /// ```
+    /// # use convert_case::Locale;
+    /// assert_eq!("ıiı", Locale::Turkish.lowercase("IİI"));
+    /// assert_eq!("i\u{307}\u{301}", Locale::Lithuanian.lowercase("Í"));
+    /// ```
+    pub fn lowercase(self, word: &str) -> String {
+        match self {
+            Locale::Turkish | Locale::Azeri => {
+                let mut dotless = String::with_capacity(word.len());
+                let mut chars = word.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        // I followed by a combining dot above is the decomposed İ
+                        'I' if chars.next_if_eq(&'\u{307}').is_some() => dotless.push('i'),
+                        'I' => dotless.push('ı'),
+                        'İ' => dotless.push('i'),
+                        _ => dotless.push(c),
+                    }
+                }
+                dotless.to_lowercase()
+            }
+            Locale::Lithuanian => {
+                let mut dotted = String::with_capacity(word.len());
+                let mut chars = word.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        'Ì' => dotted.push_str("i\u{307}\u{300}"),
+                        'Í' => dotted.push_str("i\u{307}\u{301}"),
+                        'Ĩ' => dotted.push_str("i\u{307}\u{303}"),
+                        'I' | 'J' | 'Į' if chars.peek().copied().is_some_and(is_accent_above) => {
+                            dotted.extend(c.to_lowercase());
+                            dotted.push('\u{307}');
+                        }
+                        _ => dotted.push(c),
+                    }
+                }
+                dotted.to_lowercase()
+            }
+            Locale::Root | Locale::Dutch | Locale::German | Locale::Greek => word.to_lowercase(),
+        }
+    }
+
+    /// Makes every letter uppercase.
+    /// ```
+    /// # use convert_case::Locale;
+    /// assert_eq!("İSTANBUL", Locale::Turkish.uppercase("istanbul"));
+    /// assert_eq!("STRAẞE", Locale::German.uppercase("straße"));
+    /// assert_eq!("STRASSE", Locale::Root.uppercase("straße"));
+    /// assert_eq!("ΑΘΗΝΑ", Locale::Greek.uppercase("Αθήνα"));
+    /// ```
+    pub fn uppercase(self, word: &str) -> String {
+        match self {
+            Locale::Turkish | Locale::Azeri => word.replace('i', "İ").to_uppercase(),
+            Locale::German => word.replace('ß', "ẞ").to_uppercase(),
+            Locale::Greek => {
+                let mut plain = String::with_capacity(word.len());
+                let mut greek = false;
+                for c in word.chars() {
+                    match c {
+                        'ά' | 'Ά' => plain.push('α'),
+                        'έ' | 'Έ' => plain.push('ε'),
+                        'ή' | 'Ή' => plain.push('η'),
+                        'ί' | 'Ί' => plain.push('ι'),
+                        'ό' | 'Ό' => plain.push('ο'),
+                        'ύ' | 'Ύ' => plain.push('υ'),
+                        'ώ' | 'Ώ' => plain.push('ω'),
+                        'ΐ' => plain.push('ϊ'),
+                        'ΰ' => plain.push('ϋ'),
+                        '\u{301}' if greek => {}
+                        '\u{344}' if greek => plain.push('\u{308}'),
+                        _ => plain.push(c),
+                    }
+                    if !is_combining(c) {
+                        greek = ('\u{370}'..='\u{3ff}').contains(&c);
+                    }
+                }
+                plain.to_uppercase()
+            }
+            Locale::Lithuanian => undot(word).to_uppercase(),
+            Locale::Root | Locale::Dutch => word.to_uppercase(),
+        }
+    }
+
+    /// Makes the first letter titlecase and the remaining letters lowercase.  The
+    /// titlecase form of most letters is uppercase, but not of digraphs like `ǆ`,
+    /// which is capitalized as `ǅ`.
+    /// ```
+    /// # use convert_case::Locale;
+    /// assert_eq!("İzmir", Locale::Turkish.capitalize("İZMİR"));
+    /// assert_eq!("IJsland", Locale::Dutch.capitalize("ijsland"));
+    /// assert_eq!("Άρτα", Locale::Greek.capitalize("ΆΡΤΑ"));
+    /// assert_eq!("ǅungla", Locale::Root.capitalize("ǆungla"));
+    /// ```
+    pub fn capitalize(self, word: &str) -> String {
+        if self == Locale::Dutch {
+            let mut chars = word.char_indices();
+            if let (Some((_, 'i' | 'I')), Some((_, 'j' | 'J'))) = (chars.next(), chars.next()) {
+                let rest = chars.next().map_or("", |(i, _)| &word[i..]);
+                return ["IJ", &rest.to_lowercase()].concat();
+            }
+        }
+
+        let mut graphemes = word.graphemes(true);
+        if let Some(first) = graphemes.next() {
+            let first = match self {
+                Locale::Turkish | Locale::Azeri => titlecase(&first.replace('i', "İ")),
+                Locale::German => titlecase(&first.replace('ß', "ẞ")),
+                Locale::Lithuanian => titlecase(&undot(first)),
+                // Accents are kept on a single capital letter
+                Locale::Root | Locale::Dutch | Locale::Greek => titlecase(first),
+            };
+            [first, self.lowercase(graphemes.as_str())].concat()
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Maps the first character of `s` to titlecase and the rest to lowercase, following
+/// the full case mappings of the Unicode standard.
+pub(crate) fn titlecase(s: &str) -> String {
+    let mut title = String::with_capacity(s.len());
+    // Writing into a string never fails
+    let _ = write_titlecase(s, &mut title);
+    title
+}
+
+/// Writes `s` into `out` as [`titlecase`] would return it.
+pub(crate) fn write_titlecase<W: fmt::Write + ?Sized>(s: &str, out: &mut W) -> fmt::Result {
+    let mut chars = s.chars();
+    let Some(c) = chars.next() else {
+        return Ok(());
+    };
+    match c {
+        'Ǆ'..='ǆ' => out.write_char('ǅ')?,
+        'Ǉ'..='ǉ' => out.write_char('ǈ')?,
+        'Ǌ'..='ǌ' => out.write_char('ǋ')?,
+        'Ǳ'..='ǳ' => out.write_char('ǲ')?,
+        // Georgian Mkhedruli letters are not capitalized
+        'ა'..='ჿ' => out.write_char(c)?,
+        // Greek letters with ypogegrammeni
+        'ᾀ'..='ᾇ' | 'ᾐ'..='ᾗ' | 'ᾠ'..='ᾧ' => {
+            out.write_char(char::from_u32(c as u32 + 8).unwrap_or(c))?
+        }
+        'ᾈ'..='ᾏ' | 'ᾘ'..='ᾟ' | 'ᾨ'..='ᾯ' | 'ᾼ' | 'ῌ' | 'ῼ' => {
+            out.write_char(c)?
+        }
+        'ᾳ' => out.write_char('ᾼ')?,
+        'ῃ' => out.write_char('ῌ')?,
+        'ῳ' => out.write_char('ῼ')?,
+        'ᾲ' | 'ᾴ' | 'ᾷ' | 'ῂ' | 'ῄ' | 'ῇ' | 'ῲ' | 'ῴ' | 'ῷ' => {
+            // The base letter is uppercase and the ypogegrammeni is kept
+            let mut base = c.to_uppercase();
+            if let Some(b) = base.next() {
+                out.write_char(b)?;
+            }
+            base.filter(|&b| b != 'Ι')
+                .try_for_each(|b| out.write_char(b))?;
+            out.write_char('\u{345}')?;
+        }
+        _ => {
+            let multi = match c {
+                'ß' => "Ss",
+                'և' => "Եւ",
+                'ﬀ' => "Ff",
+                'ﬁ' => "Fi",
+                'ﬂ' => "Fl",
+                'ﬃ' => "Ffi",
+                'ﬄ' => "Ffl",
+                'ﬅ' | 'ﬆ' => "St",
+                'ﬓ' => "Մն",
+                'ﬔ' => "Մե",
+                'ﬕ' => "Մի",
+                'ﬖ' => "Վն",
+                'ﬗ' => "Մխ",
+                _ => "",
+            };
+            if multi.is_empty() {
+                c.to_uppercase().try_for_each(|u| out.write_char(u))?;
+            } else {
+                out.write_str(multi)?;
+            }
+        }
+    }
+    write_lowercase(chars.as_str(), out)
+}
+
+/// Writes `s` into `out` as [`str::to_lowercase`] would return it.  Only strings with a
+/// capital sigma, whose lowercase form depends on the letters around it, are lowercased
+/// into a new string first.
+pub(crate) fn write_lowercase<W: fmt::Write + ?Sized>(s: &str, out: &mut W) -> fmt::Result {
+    if s.contains('Σ') {
+        return out.write_str(&s.to_lowercase());
+    }
+    s.chars()
+        .flat_map(char::to_lowercase)
+        .try_for_each(|c| out.write_char(c))
+}
+
+/// Writes `s` into `out` as [`str::to_uppercase`] would return it.
+pub(crate) fn write_uppercase<W: fmt::Write + ?Sized>(s: &str, out: &mut W) -> fmt::Result {
+    s.chars()
+        .flat_map(char::to_uppercase)
+        .try_for_each(|c| out.write_char(c))
+}
+
+/// Whether `c` is a titlecase letter, like `ǅ`, which is neither uppercase nor lowercase.
+pub(crate) fn is_titlecase(c: char) -> bool {
+    matches!(
+        c,
+        'ǅ' | 'ǈ' | 'ǋ' | 'ǲ' | 'ᾈ'..='ᾏ' | 'ᾘ'..='ᾟ' | 'ᾨ'..='ᾯ' | 'ᾼ' | 'ῌ' | 'ῼ'
+    )
+}
+
+/// Removes the dot above that follows soft-dotted letters like `i` and `j`.
+fn undot(word: &str) -> String {
+    let mut undotted = String::with_capacity(word.len());
+    let mut soft_dotted = false;
+    for c in word.chars() {
+        if c == '\u{307}' && soft_dotted {
+            continue;
+        }
+        undotted.push(c);
+        if !is_combining(c) {
+            soft_dotted = matches!(c, 'i' | 'j' | 'į' | 'ị' | 'ɨ');
+        }
+    }
+    undotted
+}
+
+/// Whether `c` is one of the common combining marks written above a letter.
+fn is_accent_above(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{314}' | '\u{33d}'..='\u{344}')
+}
+
+/// Whether `c` is a combining diacritical mark.
+fn is_combining(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn root_matches_std() {
+        for word in ["ὈΔΥΣΣΕΎΣ", "İstanbul", "straße", "ijs", "Í"] {
+            assert_eq!(word.to_lowercase(), Locale::Root.lowercase(word));
+            assert_eq!(word.to_uppercase(), Locale::Root.uppercase(word));
+        }
+    }
+
+    #[test]
+    fn turkish_dotted_i() {
+        for locale in [Locale::Turkish, Locale::Azeri] {
+            assert_eq!("DİYARBAKIR", locale.uppercase("diyarbakır"));
+            assert_eq!("diyarbakır", locale.lowercase("DİYARBAKIR"));
+            assert_eq!("i", locale.lowercase("I\u{307}"));
+        }
+    }
+
+    #[test]
+    fn dutch_ij() {
+        assert_eq!("IJmuiden", Locale::Dutch.capitalize("IJMUIDEN"));
+        assert_eq!("Ijs", Locale::Root.capitalize("ijs"));
+        assert_eq!("IJ", Locale::Dutch.capitalize("ij"));
+        assert_eq!("Inkt", Locale::Dutch.capitalize("inkt"));
+    }
+
+    #[test]
+    fn greek_keeps_diaeresis() {
+        assert_eq!("ΠΡΩΤΕΪΝΗ", Locale::Greek.uppercase("πρωτεΐνη"));
+        assert_eq!("ΚΑΛΗΜΕΡΑ", Locale::Greek.uppercase("καλημέρα"));
+    }
+
+    #[test]
+    fn lithuanian_dot_above() {
+        assert_eq!("i\u{307}\u{300}", Locale::Lithuanian.lowercase("Ì"));
+        assert_eq!("j\u{307}\u{303}", Locale::Lithuanian.lowercase("J\u{303}"));
+        assert_eq!("J\u{303}", Locale::Lithuanian.uppercase("j\u{307}\u{303}"));
+        assert_eq!("I\u{301}", Locale::Lithuanian.uppercase("i\u{307}\u{301}"));
+    }
+
+    #[test]
+    fn titlecase_digraphs() {
+        assert_eq!("ǅ", titlecase("Ǆ"));
+        assert_eq!("ǈubljana", titlecase("ǉubljana"));
+        assert_eq!("ǋ", titlecase("ǋ"));
+        assert_eq!("ǲ", titlecase("ǳ"));
+        assert!(is_titlecase('ǲ'));
+        assert!(!is_titlecase('Ǳ'));
+    }
+
+    #[test]
+    fn titlecase_differs_from_uppercase() {
+        assert_eq!("Ss", titlecase("ß"));
+        assert_eq!("Fi", titlecase("ﬁ"));
+        assert_eq!("ᾈ", titlecase("ᾀ"));
+        assert_eq!("ᾼ", titlecase("ᾳ"));
+        assert_eq!("Ά\u{345}", titlecase("ᾴ"));
+        assert_eq!("ა", titlecase("ა"));
+        assert_eq!("A\u{301}", titlecase("a\u{301}"));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("", Locale::Dutch.capitalize(""));
+        assert_eq!("", Locale::Greek.uppercase(""));
+    }
+}
@@ -0,0 +1,315 @@
+//! Naming conventions for each kind of symbol in a programming language.
+//!
+//! Most languages use different cases for different kinds of symbols.  Rust uses pascal
+//! case for types, snake case for functions and constant case for constants.  A
+//! [`NamingConvention`] knows which [`Case`] to use for each [`SymbolKind`], along with how the
+//! language writes initialisms.
+//! ```
+//! use convert_case::naming::{NamingConvention, SymbolKind};
+//!
+//! let rust = NamingConvention::Rust;
+//! assert_eq!("HttpServer", rust.name("http server", SymbolKind::Type));
+//! assert_eq!("start_server", rust.name("start server", SymbolKind::Function));
+//! assert_eq!("MAX_RETRIES", rust.name("max retries", SymbolKind::Constant));
+//!
+//! let go = NamingConvention::Go { exported: true };
+//! assert_eq!("HTTPServer", go.name("http server", SymbolKind::Type));
+//! let go = NamingConvention::Go { exported: false };
+//! assert_eq!("userID", go.name("user id", SymbolKind::Variable));
+//! ```
+
+use crate::acronym::AcronymStyle;
+use crate::sanitize::Language;
+use crate::{Case, Converter};
+
+use alloc::string::String;
+
+/// The kind of symbol being named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// Structs, classes, interfaces, traits, enums, messages and other types.
+    Type,
+    /// Functions, methods and procedures.
+    Function,
+    /// Local variables and parameters.
+    Variable,
+    /// Constants and static values.
+    Constant,
+    /// Members of an enumeration.
+    EnumVariant,
+    /// Modules, packages and namespaces.
+    Module,
+    /// Fields and properties of a type.
+    Field,
+}
+
+impl SymbolKind {
+    /// All the kinds of symbols.
+    pub const fn all() -> &'static [SymbolKind] {
+        use SymbolKind::*;
+        &[
+            Type,
+            Function,
+            Variable,
+            Constant,
+            EnumVariant,
+            Module,
+            Field,
+        ]
+    }
+}
+
+/// The naming rules of a programming language.
+///
+/// The case used for each kind of symbol is outlined below.
+///
+/// | Convention | Type | Function | Variable | Constant | EnumVariant | Module | Field |
+/// | --- | --- | --- | --- | --- | --- | --- | --- |
+/// | Rust | Pascal | Snake | Snake | Constant | Pascal | Snake | Snake |
+/// | Go, exported | Pascal | Pascal | Pascal | Pascal | Pascal | Flat | Pascal |
+/// | Go, unexported | Camel | Camel | Camel | Camel | Camel | Flat | Camel |
+/// | Python | Pascal | Snake | Snake | Constant | Constant | Snake | Snake |
+/// | Java, Kotlin | Pascal | Camel | Camel | Constant | Constant | Flat | Camel |
+/// | CSharp | Pascal | Pascal | Camel | Pascal | Pascal | Pascal | Pascal |
+/// | JavaScript | Pascal | Camel | Camel | Constant | Pascal | Camel | Camel |
+/// | GraphQL | Pascal | Camel | Camel | Constant | Constant | Pascal | Camel |
+/// | Protobuf | Pascal | Pascal | Snake | Constant | Constant | Snake | Snake |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamingConvention {
+    /// Rust, following the Rust API guidelines.
+    Rust,
+    /// Go, where the case of the first letter determines whether a symbol is
+    /// exported from its package.  Initialisms are uppercase.
+    Go {
+        /// Whether the symbol is visible outside its package.
+        exported: bool,
+    },
+    /// Python, following PEP 8.
+    Python,
+    /// Java.
+    Java,
+    /// Kotlin.
+    Kotlin,
+    /// C#, following the .NET design guidelines.  Initialisms of two letters are uppercase.
+    CSharp,
+    /// JavaScript and TypeScript.
+    JavaScript,
+    /// GraphQL schemas.  Functions are the fields of root operation types.
+    GraphQL,
+    /// Protocol buffers.  Functions are the methods of services.
+    Protobuf,
+}
+
+impl NamingConvention {
+    /// All the naming conventions.
+    pub const fn all() -> &'static [NamingConvention] {
+        use NamingConvention::*;
+        &[
+            Rust,
+            Go { exported: true },
+            Go { exported: false },
+            Python,
+            Java,
+            Kotlin,
+            CSharp,
+            JavaScript,
+            GraphQL,
+            Protobuf,
+        ]
+    }
+
+    /// The case used for the given kind of symbol.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case::naming::{NamingConvention, SymbolKind};
+    ///
+    /// assert_eq!(Case::Constant, NamingConvention::Python.case(SymbolKind::Constant));
+    /// assert_eq!(Case::Pascal, NamingConvention::CSharp.case(SymbolKind::Function));
+    /// ```
+    pub const fn case(self, kind: SymbolKind) -> Case<'static> {
+        use NamingConvention::*;
+        use SymbolKind::*;
+        match (self, kind) {
+            (Go { .. }, Module) => Case::Flat,
+            (Go { exported: true }, _) => Case::Pascal,
+            (Go { exported: false }, _) => Case::Camel,
+
+            (_, Type) => Case::Pascal,
+
+            (Rust | Python, Function | Variable | Module | Field) => Case::Snake,
+            (Rust, EnumVariant) => Case::Pascal,
+
+            (Java | Kotlin, Function | Variable | Field) => Case::Camel,
+            (Java | Kotlin, Module) => Case::Flat,
+
+            (CSharp, Variable) => Case::Camel,
+            (CSharp, _) => Case::Pascal,
+
+            (JavaScript, Function | Variable | Module | Field) => Case::Camel,
+            (JavaScript, EnumVariant) => Case::Pascal,
+
+            (GraphQL, Function | Variable | Field) => Case::Camel,
+            (GraphQL, Module) => Case::Pascal,
+
+            (Protobuf, Function) => Case::Pascal,
+            (Protobuf, Variable | Module | Field) => Case::Snake,
+
+            (_, Constant | EnumVariant) => Case::Constant,
+        }
+    }
+
+    /// How initialisms are written when they are capitalized.
+    pub const fn acronym_style(self) -> AcronymStyle {
+        match self {
+            NamingConvention::Go { .. } => AcronymStyle::Upper,
+            NamingConvention::CSharp => AcronymStyle::TwoLetterUpper,
+            _ => AcronymStyle::Capitalized,
+        }
+    }
+
+    /// The language whose reserved words are escaped by [`name`](NamingConvention::name),
+    /// if keywords are known for this convention.  GraphQL and Protobuf have none, so
+    /// their names are not escaped.
+    pub const fn language(self) -> Option<Language> {
+        use NamingConvention::*;
+        match self {
+            Rust => Some(Language::Rust),
+            Go { .. } => Some(Language::Go),
+            Python => Some(Language::Python),
+            Java => Some(Language::Java),
+            Kotlin => Some(Language::Kotlin),
+            CSharp => Some(Language::CSharp),
+            JavaScript => Some(Language::JavaScript),
+            GraphQL | Protobuf => None,
+        }
+    }
+
+    /// A converter into the case used for the given kind of symbol.  When the
+    /// convention has a [language](NamingConvention::language), characters that
+    /// cannot appear in an identifier are treated as boundaries between words and
+    /// dropped, as in [`Language::sanitize`].
+    /// ```
+    /// use convert_case::naming::{NamingConvention, SymbolKind};
+    ///
+    /// let conv = NamingConvention::CSharp.converter(SymbolKind::Type);
+    /// assert_eq!("IOStream", conv.convert("io_stream"));
+    /// assert_eq!("XmlReader", conv.convert("xml_reader"));
+    /// ```
+    pub fn converter(self, kind: SymbolKind) -> Converter {
+        let conv = Converter::new()
+            .to_case(self.case(kind))
+            .set_acronym_style(self.acronym_style());
+        match self.language() {
+            Some(language) => conv.add_boundary(language.illegal_boundary()),
+            None => conv,
+        }
+    }
+
+    /// Converts `s` into the name of a symbol of the given kind.  When the convention
+    /// has a [language](NamingConvention::language), the name is also
+    /// [escaped](Language::escape) so it is a valid identifier.
+    /// ```
+    /// use convert_case::naming::{NamingConvention, SymbolKind};
+    ///
+    /// assert_eq!("r#type", NamingConvention::Rust.name("type", SymbolKind::Field));
+    /// assert_eq!("order_status", NamingConvention::Protobuf.name("OrderStatus", SymbolKind::Field));
+    /// assert_eq!("ORDER_STATUS_SHIPPED", NamingConvention::GraphQL.name("orderStatus shipped", SymbolKind::EnumVariant));
+    /// ```
+    pub fn name<T: AsRef<str>>(self, s: T, kind: SymbolKind) -> String {
+        let name = self.converter(kind).convert(s);
+        match self.language() {
+            Some(language) => language.escape(name),
+            None => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    fn names(convention: NamingConvention, s: &str) -> Vec<String> {
+        SymbolKind::all()
+            .iter()
+            .map(|&kind| convention.name(s, kind))
+            .collect()
+    }
+
+    #[test]
+    fn rust() {
+        assert_eq!(
+            vec![
+                "UserAccount",
+                "user_account",
+                "user_account",
+                "USER_ACCOUNT",
+                "UserAccount",
+                "user_account",
+                "user_account",
+            ],
+            names(NamingConvention::Rust, "user account")
+        );
+    }
+
+    #[test]
+    fn go() {
+        assert_eq!(
+            vec!["UserID", "UserID", "UserID", "UserID", "UserID", "userid", "UserID"],
+            names(NamingConvention::Go { exported: true }, "user id")
+        );
+        assert_eq!(
+            vec!["userID", "userID", "userID", "userID", "userID", "userid", "userID"],
+            names(NamingConvention::Go { exported: false }, "user id")
+        );
+    }
+
+    #[test]
+    fn csharp() {
+        assert_eq!(
+            vec![
+                "IOStream", "IOStream", "ioStream", "IOStream", "IOStream", "IOStream", "IOStream"
+            ],
+            names(NamingConvention::CSharp, "io stream")
+        );
+    }
+
+    #[test]
+    fn keywords_escaped() {
+        assert_eq!(
+            "class_",
+            NamingConvention::Python.name("class", SymbolKind::Variable)
+        );
+        assert_eq!(
+            "package_",
+            NamingConvention::Java.name("package", SymbolKind::Module)
+        );
+        assert_eq!(
+            "func_",
+            NamingConvention::Go { exported: false }.name("func", SymbolKind::Function)
+        );
+        assert_eq!(
+            "`fun`",
+            NamingConvention::Kotlin.name("fun", SymbolKind::Function)
+        );
+        assert_eq!(
+            "@class",
+            NamingConvention::CSharp.name("class", SymbolKind::Variable)
+        );
+        // No keywords are known for GraphQL
+        assert_eq!(
+            "type",
+            NamingConvention::GraphQL.name("type", SymbolKind::Field)
+        );
+    }
+
+    #[test]
+    fn every_kind_has_a_case() {
+        for &convention in NamingConvention::all() {
+            for &kind in SymbolKind::all() {
+                assert!(!convention.name("some name", kind).is_empty());
+            }
+        }
+    }
+}
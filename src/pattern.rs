@@ -3,15 +3,34 @@
 //! A pattern is a function that maps a list of words into another list
 //! after changing the casing of each letter.  How a patterns mutates
 //! each letter can be dependent on the word the letters are present in.
+//!
+//! Any function or closure with the signature `Fn(&[&str]) -> Vec<String>` is a
+//! [`Pattern`], as is any type that implements the trait, which lets a pattern
+//! carry its own configuration.
 
 #[cfg(feature = "random")]
 use rand::prelude::*;
 
+use crate::locale::{is_titlecase, write_lowercase, write_titlecase, write_uppercase};
+use crate::Locale;
+
+use alloc::boxed::Box;
 use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+use core::mem::size_of_val;
+use core::ptr;
 
 use unicode_segmentation::UnicodeSegmentation;
 
+mod title;
+
+pub use title::{
+    StyleGuide, TitleCase, FRENCH_SMALL_WORDS, GERMAN_SMALL_WORDS, SPANISH_SMALL_WORDS,
+};
+
 mod word_pattern {
     use super::*;
 
@@ -24,27 +43,398 @@ mod word_pattern {
     }
 
     pub fn capital(word: &str) -> String {
+        Locale::Root.capitalize(word)
+    }
+
+    pub fn toggle(word: &str) -> String {
+        toggle_in(Locale::Root, word)
+    }
+
+    pub fn toggle_in(locale: Locale, word: &str) -> String {
         let mut graphemes = word.graphemes(true);
 
         if let Some(c) = graphemes.next() {
-            [c.to_uppercase(), graphemes.as_str().to_lowercase()].concat()
+            [locale.lowercase(c), locale.uppercase(graphemes.as_str())].concat()
         } else {
             String::new()
         }
     }
+}
 
-    pub fn toggle(word: &str) -> String {
-        let mut graphemes = word.graphemes(true);
+/// Mutates a list of words.
+///
+/// This is implemented for every function and closure `Fn(&[&str]) -> Vec<String>`,
+/// including the functions in this module.  Implement it for your own type when a
+/// pattern needs configuration.
+/// ```
+/// use convert_case::{Case, Converter, pattern::Pattern};
+///
+/// // Uppercases only the words it is given
+/// struct Shout(Vec<&'static str>);
+///
+/// impl Pattern for Shout {
+///     fn mutate(&self, words: &[&str]) -> Vec<String> {
+///         words
+///             .iter()
+///             .map(|w| {
+///                 if self.0.contains(w) {
+///                     w.to_uppercase()
+///                 } else {
+///                     w.to_lowercase()
+///                 }
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// let conv = Converter::new()
+///     .to_case(Case::Snake)
+///     .set_pattern(Shout(vec!["now"]));
+/// assert_eq!("do_it_NOW", conv.convert("Do it now"));
+/// ```
+pub trait Pattern: Any + Send + Sync {
+    /// Mutates each of the words.
+    fn mutate(&self, words: &[&str]) -> Vec<String>;
 
-        if let Some(c) = graphemes.next() {
-            [c.to_lowercase(), graphemes.as_str().to_uppercase()].concat()
+    /// Mutates each of the words and writes them into `out`, separated by `delim`.  This
+    /// is used by [`Converter::convert_into`](crate::Converter::convert_into).  By default
+    /// the words are collected and the result of [`mutate`](Pattern::mutate) is written, so
+    /// implement this method to mutate words without allocating.
+    /// ```
+    /// use convert_case::{Converter, pattern::Pattern};
+    /// use core::fmt;
+    ///
+    /// struct AsciiLower;
+    ///
+    /// impl Pattern for AsciiLower {
+    ///     fn mutate(&self, words: &[&str]) -> Vec<String> {
+    ///         words.iter().map(|w| w.to_ascii_lowercase()).collect()
+    ///     }
+    ///
+    ///     fn mutate_into(
+    ///         &self,
+    ///         words: &mut dyn Iterator<Item = &str>,
+    ///         delim: &str,
+    ///         out: &mut dyn fmt::Write,
+    ///     ) -> fmt::Result {
+    ///         for (i, word) in words.enumerate() {
+    ///             if i > 0 {
+    ///                 out.write_str(delim)?;
+    ///             }
+    ///             for c in word.chars() {
+    ///                 out.write_char(c.to_ascii_lowercase())?;
+    ///             }
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let conv = Converter::new().set_pattern(AsciiLower).set_delim("_");
+    /// let mut buf = String::new();
+    /// conv.convert_into("Hello World", &mut buf).unwrap();
+    /// assert_eq!("hello_world", buf);
+    /// ```
+    fn mutate_into(
+        &self,
+        words: &mut dyn Iterator<Item = &str>,
+        delim: &str,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let words: Vec<&str> = words.collect();
+        for (i, word) in self.mutate(&words).iter().enumerate() {
+            if i > 0 {
+                out.write_str(delim)?;
+            }
+            out.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F> Pattern for F
+where
+    F: Fn(&[&str]) -> Vec<String> + Send + Sync + 'static,
+{
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        self(words)
+    }
+}
+
+impl Pattern for &'static dyn Pattern {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        (**self).mutate(words)
+    }
+
+    fn mutate_into(
+        &self,
+        words: &mut dyn Iterator<Item = &str>,
+        delim: &str,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        (**self).mutate_into(words, delim, out)
+    }
+}
+
+impl Pattern for Box<dyn Pattern> {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        (**self).mutate(words)
+    }
+
+    fn mutate_into(
+        &self,
+        words: &mut dyn Iterator<Item = &str>,
+        delim: &str,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        (**self).mutate_into(words, delim, out)
+    }
+}
+
+impl Pattern for Arc<dyn Pattern> {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        (**self).mutate(words)
+    }
+
+    fn mutate_into(
+        &self,
+        words: &mut dyn Iterator<Item = &str>,
+        delim: &str,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        (**self).mutate_into(words, delim, out)
+    }
+}
+
+/// Looks through references, boxes and `Arc`s to the pattern that mutates the words.
+pub(crate) fn inner(pattern: &dyn Pattern) -> &dyn Pattern {
+    let any: &dyn Any = pattern;
+    if let Some(p) = any.downcast_ref::<&'static dyn Pattern>() {
+        inner(*p)
+    } else if let Some(p) = any.downcast_ref::<Box<dyn Pattern>>() {
+        inner(&**p)
+    } else if let Some(p) = any.downcast_ref::<Arc<dyn Pattern>>() {
+        inner(&**p)
+    } else {
+        pattern
+    }
+}
+
+/// Whether two patterns are the same.  Patterns without any data, such as functions,
+/// are the same when they have the same type.  Otherwise they must be the same value.
+pub(crate) fn same(a: &dyn Pattern, b: &dyn Pattern) -> bool {
+    let (a, b) = (inner(a), inner(b));
+    let same_type = (a as &dyn Any).type_id() == (b as &dyn Any).type_id();
+    same_type && (size_of_val(a) == 0 || ptr::addr_eq(a, b))
+}
+
+/// Which letter of a word is capitalized by the [`capital`], [`camel`], [`sentence`]
+/// and [`toggle`] patterns when used by a [`Converter`](crate::Converter).
+/// ```
+/// use convert_case::{Case, Converter, pattern::Capitalize};
+///
+/// let conv = Converter::new().from_case(Case::Lower).to_case(Case::Title);
+/// assert_eq!("¿qué Pasa?", conv.convert("¿qué pasa?"));
+///
+/// let conv = conv.set_capitalize(Capitalize::FirstLetter);
+/// assert_eq!("¿Qué Pasa?", conv.convert("¿qué pasa?"));
+/// assert_eq!("'Twas (Beta) 1St", conv.convert("'twas (beta) 1st"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Capitalize {
+    /// The first grapheme of the word is changed, even when it is not a letter.
+    #[default]
+    FirstGrapheme,
+    /// Leading graphemes without a cased letter, such as digits and punctuation, are
+    /// skipped, and the first cased letter is changed.
+    FirstLetter,
+}
+
+impl Capitalize {
+    /// Splits `word` before the grapheme that is capitalized.
+    fn split(self, word: &str) -> (&str, &str) {
+        let at = match self {
+            Capitalize::FirstGrapheme => 0,
+            Capitalize::FirstLetter => word
+                .grapheme_indices(true)
+                .find(|(_, g)| {
+                    g.chars()
+                        .any(|c| c.is_lowercase() || c.is_uppercase() || is_titlecase(c))
+                })
+                .map_or(word.len(), |(i, _)| i),
+        };
+        word.split_at(at)
+    }
+}
+
+/// Mutates words with `pattern`, following the case mappings of `locale` and capitalizing
+/// the letter chosen by `capitalize` when it is one of the [`lowercase`], [`uppercase`],
+/// [`capital`], [`camel`], [`sentence`] or [`toggle`] patterns.  Any other pattern
+/// mutates the words as usual.
+pub(crate) fn mutate_in(
+    pattern: &dyn Pattern,
+    words: &[&str],
+    locale: Locale,
+    capitalize: Capitalize,
+) -> Vec<String> {
+    type WordPattern = fn(Locale, &str) -> String;
+    let (first, rest): (WordPattern, WordPattern) =
+        if locale == Locale::Root && capitalize == Capitalize::FirstGrapheme {
+            return pattern.mutate(words);
+        } else if same(pattern, &lowercase) {
+            (Locale::lowercase, Locale::lowercase)
+        } else if same(pattern, &uppercase) {
+            (Locale::uppercase, Locale::uppercase)
+        } else if same(pattern, &capital) {
+            (Locale::capitalize, Locale::capitalize)
+        } else if same(pattern, &camel) {
+            (Locale::lowercase, Locale::capitalize)
+        } else if same(pattern, &sentence) {
+            (Locale::capitalize, Locale::lowercase)
+        } else if same(pattern, &toggle) {
+            (word_pattern::toggle_in, word_pattern::toggle_in)
         } else {
-            String::new()
+            return pattern.mutate(words);
+        };
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let (leading, word) = capitalize.split(word);
+            let word = if i == 0 { first } else { rest }(locale, word);
+            [leading, &word].concat()
+        })
+        .collect()
+}
+
+/// How one of the patterns in this module changes the case of a single word.
+#[derive(Clone, Copy)]
+enum WordCase {
+    Keep,
+    Lower,
+    Upper,
+    Capital,
+    Toggle,
+    Alternating,
+}
+
+/// Writes words mutated by one of the patterns in this module into a [`fmt::Write`]
+/// one at a time, without allocating.  Only the root [`Locale`] is followed.
+pub(crate) struct WordWriter {
+    first: WordCase,
+    rest: WordCase,
+    started: bool,
+    // Whether the next letter is uppercase in the alternating pattern
+    upper: bool,
+}
+
+impl WordWriter {
+    /// Returns `None` unless `pattern` is [`noop`], [`lowercase`], [`uppercase`],
+    /// [`capital`], [`camel`], [`sentence`], [`toggle`] or [`alternating`].
+    pub(crate) fn new(pattern: &dyn Pattern) -> Option<Self> {
+        use WordCase::*;
+        let (first, rest) = if same(pattern, &noop) {
+            (Keep, Keep)
+        } else if same(pattern, &lowercase) {
+            (Lower, Lower)
+        } else if same(pattern, &uppercase) {
+            (Upper, Upper)
+        } else if same(pattern, &capital) {
+            (Capital, Capital)
+        } else if same(pattern, &camel) {
+            (Lower, Capital)
+        } else if same(pattern, &sentence) {
+            (Capital, Lower)
+        } else if same(pattern, &toggle) {
+            (Toggle, Toggle)
+        } else if same(pattern, &alternating) {
+            (Alternating, Alternating)
+        } else {
+            return None;
+        };
+        Some(WordWriter {
+            first,
+            rest,
+            started: false,
+            upper: false,
+        })
+    }
+
+    /// Mutates the next word and writes it into `out`.
+    pub(crate) fn write<W: fmt::Write + ?Sized>(&mut self, word: &str, out: &mut W) -> fmt::Result {
+        let case = if self.started { self.rest } else { self.first };
+        self.started = true;
+        let mut graphemes = word.graphemes(true);
+        match case {
+            WordCase::Keep => out.write_str(word),
+            WordCase::Lower => write_lowercase(word, out),
+            WordCase::Upper => write_uppercase(word, out),
+            WordCase::Capital => match graphemes.next() {
+                Some(first) => {
+                    write_titlecase(first, out)?;
+                    write_lowercase(graphemes.as_str(), out)
+                }
+                None => Ok(()),
+            },
+            WordCase::Toggle => match graphemes.next() {
+                Some(first) => {
+                    write_lowercase(first, out)?;
+                    write_uppercase(graphemes.as_str(), out)
+                }
+                None => Ok(()),
+            },
+            WordCase::Alternating => {
+                for letter in word.chars() {
+                    if letter.is_uppercase() || letter.is_lowercase() {
+                        if self.upper {
+                            letter.to_uppercase().try_for_each(|c| out.write_char(c))?;
+                        } else {
+                            letter.to_lowercase().try_for_each(|c| out.write_char(c))?;
+                        }
+                        self.upper = !self.upper;
+                    } else {
+                        out.write_char(letter)?;
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
 
-pub type Pattern = fn(&[&str]) -> Vec<String>;
+/// Mutates words with a generator seeded by `seed` when `pattern` is [`random`] or
+/// [`pseudo_random`].  Returns `None` for any other pattern.
+#[cfg(feature = "random")]
+pub(crate) fn mutate_seeded(
+    pattern: &dyn Pattern,
+    words: &[&str],
+    seed: u64,
+) -> Option<Vec<String>> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    if same(pattern, &random) {
+        Some(random_with(words, &mut rng))
+    } else if same(pattern, &pseudo_random) {
+        Some(pseudo_random_with(words, &mut rng))
+    } else {
+        None
+    }
+}
+
+/// The patterns defined in this module along with their names.
+#[cfg(feature = "serde")]
+pub(crate) const NAMED: &[(&str, &dyn Pattern)] = &[
+    ("noop", &noop),
+    ("lowercase", &lowercase),
+    ("uppercase", &uppercase),
+    ("capital", &capital),
+    ("camel", &camel),
+    ("sentence", &sentence),
+    ("toggle", &toggle),
+    ("alternating", &alternating),
+    #[cfg(feature = "random")]
+    ("random", &random),
+    #[cfg(feature = "random")]
+    ("pseudo_random", &pseudo_random),
+];
 
 /// The no-op pattern performs no mutations.
 /// ```
@@ -90,6 +480,8 @@ pub fn uppercase(words: &[&str]) -> Vec<String> {
 
 /// Makes the first letter of each word uppercase
 /// and the remaining letters of each word lowercase.
+/// The first letter is mapped to titlecase, which is
+/// the same as uppercase except for digraphs like `ǆ`.
 /// ```
 /// # use convert_case::pattern;
 /// assert_eq!(
@@ -119,9 +511,9 @@ pub fn camel(words: &[&str]) -> Vec<String> {
         .enumerate()
         .map(|(i, &word)| {
             if i == 0 {
-                word_pattern::lowercase(&word)
+                word_pattern::lowercase(word)
             } else {
-                word_pattern::capital(&word)
+                word_pattern::capital(word)
             }
         })
         .collect()
@@ -142,9 +534,9 @@ pub fn sentence(words: &[&str]) -> Vec<String> {
         .enumerate()
         .map(|(i, &word)| {
             if i == 0 {
-                word_pattern::capital(&word)
+                word_pattern::capital(word)
             } else {
-                word_pattern::lowercase(&word)
+                word_pattern::lowercase(word)
             }
         })
         .collect()
@@ -223,13 +615,30 @@ pub fn alternating(words: &[&str]) -> Vec<String> {
 /// ```
 #[cfg(feature = "random")]
 pub fn random(words: &[&str]) -> Vec<String> {
-    let mut rng = rand::thread_rng();
+    random_with(words, &mut rand::rng())
+}
+
+/// Lowercases or uppercases each letter uniformly randomly, using the given
+/// random number generator.  This is the same as [`random`], which uses the
+/// thread-local generator, but lets the output be reproduced from a seed.
+/// ```
+/// # use convert_case::pattern;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = ["Case", "CONVERSION", "library"];
+/// assert_eq!(
+///     pattern::random_with(&words, &mut StdRng::seed_from_u64(7)),
+///     pattern::random_with(&words, &mut StdRng::seed_from_u64(7)),
+/// );
+/// ```
+#[cfg(feature = "random")]
+pub fn random_with<R: Rng + ?Sized>(words: &[&str], rng: &mut R) -> Vec<String> {
     words
         .iter()
         .map(|word| {
             word.chars()
                 .map(|letter| {
-                    if rng.gen::<f32>() > 0.5 {
+                    if rng.random::<f32>() > 0.5 {
                         letter.to_uppercase().to_string()
                     } else {
                         letter.to_lowercase().to_string()
@@ -260,8 +669,24 @@ pub fn random(words: &[&str]) -> Vec<String> {
 /// ```
 #[cfg(feature = "random")]
 pub fn pseudo_random(words: &[&str]) -> Vec<String> {
-    let mut rng = rand::thread_rng();
+    pseudo_random_with(words, &mut rand::rng())
+}
 
+/// Case each letter in random-like patterns, using the given random number generator.
+/// This is the same as [`pseudo_random`], which uses the thread-local generator, but
+/// lets the output be reproduced from a seed.
+/// ```
+/// # use convert_case::pattern;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = ["Case", "CONVERSION", "library"];
+/// assert_eq!(
+///     pattern::pseudo_random_with(&words, &mut StdRng::seed_from_u64(7)),
+///     pattern::pseudo_random_with(&words, &mut StdRng::seed_from_u64(7)),
+/// );
+/// ```
+#[cfg(feature = "random")]
+pub fn pseudo_random_with<R: Rng + ?Sized>(words: &[&str], rng: &mut R) -> Vec<String> {
     // Keeps track of when to alternate
     let mut alt: Option<bool> = None;
     words
@@ -272,7 +697,7 @@ pub fn pseudo_random(words: &[&str]) -> Vec<String> {
                     match alt {
                         // No existing pattern, start one
                         None => {
-                            if rng.gen::<f32>() > 0.5 {
+                            if rng.random::<f32>() > 0.5 {
                                 alt = Some(false); // Make the next char lower
                                 letter.to_uppercase().to_string()
                             } else {
@@ -319,6 +744,19 @@ mod test {
         }
     }
 
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_randoms_repeat() {
+        let words = ["abcdefg", "hijklmnop", "qrstuv", "wxyz"];
+        for pattern in [&random as &dyn Pattern, &pseudo_random] {
+            let first = mutate_seeded(pattern, &words, 42);
+            assert!(first.is_some());
+            assert_eq!(first, mutate_seeded(pattern, &words, 42));
+            assert_ne!(first, mutate_seeded(pattern, &words, 43));
+        }
+        assert_eq!(None, mutate_seeded(&lowercase, &words, 42));
+    }
+
     #[cfg(feature = "random")]
     #[test]
     fn randoms_are_random() {
@@ -332,6 +770,92 @@ mod test {
         }
     }
 
+    #[test]
+    fn same_patterns() {
+        let lower: &'static dyn Pattern = &lowercase;
+        assert!(same(&lowercase, &lowercase));
+        assert!(same(&lowercase, &lower));
+        let boxed: Box<dyn Pattern> = Box::new(lower);
+        assert!(same(&boxed, &lowercase));
+        assert!(!same(&lowercase, &uppercase));
+    }
+
+    #[test]
+    fn same_stateful_patterns() {
+        struct Prefix(&'static str);
+        impl Pattern for Prefix {
+            fn mutate(&self, words: &[&str]) -> Vec<String> {
+                words.iter().map(|w| [self.0, w].concat()).collect()
+            }
+        }
+        let (a, b) = (Prefix("a"), Prefix("b"));
+        assert!(same(&a, &a));
+        assert!(!same(&a, &b));
+        assert_eq!(vec!["bx"], b.mutate(&["x"]));
+    }
+
+    #[test]
+    fn localized_patterns() {
+        let words = ["istanbul", "izmir"];
+        assert_eq!(
+            vec!["istanbul", "İzmir"],
+            mutate_in(&camel, &words, Locale::Turkish, Capitalize::FirstGrapheme)
+        );
+        assert_eq!(
+            vec!["İstanbul", "izmir"],
+            mutate_in(
+                &sentence,
+                &words,
+                Locale::Turkish,
+                Capitalize::FirstGrapheme
+            )
+        );
+        assert_eq!(
+            vec!["İSTANBUL", "İZMİR"],
+            mutate_in(&uppercase, &words, Locale::Azeri, Capitalize::FirstGrapheme)
+        );
+        // Other patterns are unaffected
+        assert_eq!(
+            alternating(&words),
+            mutate_in(
+                &alternating,
+                &words,
+                Locale::Turkish,
+                Capitalize::FirstGrapheme
+            )
+        );
+    }
+
+    #[test]
+    fn capitalize_first_letter() {
+        let words = ["¿qué", "'twas", "(beta)", "1st", "...", "Ωmega"];
+        let mutate = |p: &dyn Pattern| mutate_in(p, &words, Locale::Root, Capitalize::FirstLetter);
+        assert_eq!(
+            vec!["¿Qué", "'Twas", "(Beta)", "1St", "...", "Ωmega"],
+            mutate(&capital)
+        );
+        assert_eq!(
+            vec!["¿qué", "'Twas", "(Beta)", "1St", "...", "Ωmega"],
+            mutate(&camel)
+        );
+        assert_eq!(
+            vec!["¿Qué", "'twas", "(beta)", "1st", "...", "ωmega"],
+            mutate(&sentence)
+        );
+        assert_eq!(
+            vec!["¿qUÉ", "'tWAS", "(bETA)", "1sT", "...", "ωMEGA"],
+            mutate(&toggle)
+        );
+        assert_eq!(uppercase(&words), mutate(&uppercase));
+    }
+
+    #[test]
+    fn capitalize_to_titlecase() {
+        assert_eq!(vec!["ǅungla", "ǈubav"], capital(&["ǄUNGLA", "ǉubav"]));
+        assert_eq!(vec!["ǆungla", "ǋego"], camel(&["ǅungla", "Ǌego"]));
+        assert_eq!(vec!["ǲ", "dz"], sentence(&["Ǳ", "DZ"]));
+    }
+
     #[test]
     fn mutate_empty_strings() {
         for word_pattern in [
@@ -340,7 +864,7 @@ mod test {
             word_pattern::capital,
             word_pattern::toggle,
         ] {
-            assert_eq!(String::new(), word_pattern(&String::new()))
+            assert_eq!(String::new(), word_pattern(""))
         }
     }
 }
@@ -0,0 +1,328 @@
+use super::{word_pattern, Pattern};
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A style guide for capitalizing titles, which determines which small words are
+/// left lowercase by [`TitleCase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleGuide {
+    /// The Associated Press Stylebook.  Articles, and conjunctions and prepositions
+    /// of three letters or fewer, are lowercase.
+    Ap,
+    /// The Chicago Manual of Style.  Articles, coordinating conjunctions and all
+    /// prepositions are lowercase, regardless of length.
+    Chicago,
+    /// The APA Publication Manual.  Articles, and conjunctions and prepositions
+    /// of three letters or fewer, are lowercase.
+    Apa,
+}
+
+impl StyleGuide {
+    /// The words left lowercase by the style guide.
+    pub const fn small_words(self) -> &'static [&'static str] {
+        match self {
+            StyleGuide::Ap => AP_SMALL_WORDS,
+            StyleGuide::Chicago => CHICAGO_SMALL_WORDS,
+            StyleGuide::Apa => APA_SMALL_WORDS,
+        }
+    }
+}
+
+const AP_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "off", "on", "or", "per",
+    "so", "the", "to", "up", "via", "yet",
+];
+
+const APA_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "if", "in", "nor", "of", "off", "on", "or",
+    "per", "so", "the", "to", "up", "via", "yet",
+];
+
+const CHICAGO_SMALL_WORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "across",
+    "after",
+    "against",
+    "along",
+    "among",
+    "an",
+    "and",
+    "around",
+    "as",
+    "at",
+    "before",
+    "behind",
+    "below",
+    "beneath",
+    "beside",
+    "between",
+    "beyond",
+    "but",
+    "by",
+    "down",
+    "during",
+    "except",
+    "for",
+    "from",
+    "in",
+    "inside",
+    "into",
+    "like",
+    "near",
+    "nor",
+    "of",
+    "off",
+    "on",
+    "onto",
+    "or",
+    "out",
+    "outside",
+    "over",
+    "past",
+    "per",
+    "since",
+    "the",
+    "through",
+    "throughout",
+    "till",
+    "to",
+    "toward",
+    "under",
+    "underneath",
+    "until",
+    "up",
+    "upon",
+    "via",
+    "with",
+    "within",
+    "without",
+];
+
+/// Articles, conjunctions and prepositions that are lowercase in French titles.
+pub const FRENCH_SMALL_WORDS: &[&str] = &[
+    "au", "aux", "avec", "d", "de", "des", "du", "en", "et", "l", "la", "le", "les", "mais", "ni",
+    "ou", "par", "pour", "sans", "sous", "sur", "un", "une", "à",
+];
+
+/// Articles, conjunctions and prepositions that are lowercase in German titles.
+pub const GERMAN_SMALL_WORDS: &[&str] = &[
+    "am", "an", "auf", "aus", "bei", "das", "dem", "den", "der", "des", "die", "ein", "eine",
+    "einem", "einen", "einer", "eines", "für", "im", "in", "mit", "nach", "oder", "und", "vom",
+    "von", "zu", "zum", "zur",
+];
+
+/// Articles, conjunctions and prepositions that are lowercase in Spanish titles.
+pub const SPANISH_SMALL_WORDS: &[&str] = &[
+    "a", "al", "con", "de", "del", "e", "el", "en", "la", "las", "lo", "los", "ni", "o", "para",
+    "por", "sin", "sobre", "u", "un", "una", "unas", "unos", "y",
+];
+
+/// Capitalizes words following the rules of a title style guide.
+///
+/// Small words such as articles, conjunctions and prepositions are lowercase, unless
+/// they are the first or last word.  Every other word is capitalized, as in
+/// [`capital`](super::capital).  Each part of a hyphenated compound is capitalized as its
+/// own word, though small words inside it are still lowercase unless they begin the first
+/// word or end the last word.
+/// ```
+/// use convert_case::{Case, Converter};
+/// use convert_case::pattern::{StyleGuide, TitleCase};
+///
+/// let conv = Converter::new()
+///     .from_case(Case::Lower)
+///     .to_case(Case::Title)
+///     .set_pattern(TitleCase::new(StyleGuide::Chicago));
+/// assert_eq!("A Tale of Two Cities", conv.convert("a tale of two cities"));
+/// assert_eq!("The Self-Driving Car Is Here", conv.convert("the self-driving car is here"));
+/// assert_eq!("Slipping through the Cracks", conv.convert("slipping through the cracks"));
+///
+/// let conv = conv.set_pattern(TitleCase::new(StyleGuide::Ap));
+/// assert_eq!("Slipping Through the Cracks", conv.convert("slipping through the cracks"));
+/// ```
+///
+/// The small words can also be replaced, such as with the lists for other languages
+/// provided in this module.
+/// ```
+/// use convert_case::{Case, Converter};
+/// use convert_case::pattern::{TitleCase, SPANISH_SMALL_WORDS};
+///
+/// let conv = Converter::new()
+///     .from_case(Case::Lower)
+///     .to_case(Case::Title)
+///     .set_pattern(TitleCase::from_small_words(SPANISH_SMALL_WORDS));
+/// assert_eq!("Cien Años de Soledad", conv.convert("cien años de soledad"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleCase {
+    small_words: Vec<Cow<'static, str>>,
+}
+
+impl TitleCase {
+    /// Creates a title case pattern that follows the given style guide.
+    pub fn new(guide: StyleGuide) -> Self {
+        TitleCase {
+            small_words: guide
+                .small_words()
+                .iter()
+                .map(|&w| Cow::Borrowed(w))
+                .collect(),
+        }
+    }
+
+    /// Creates a title case pattern that leaves the given words lowercase.
+    pub fn from_small_words<T: AsRef<str>>(words: &[T]) -> Self {
+        TitleCase {
+            small_words: words
+                .iter()
+                .map(|w| Cow::Owned(w.as_ref().to_lowercase()))
+                .collect(),
+        }
+    }
+
+    /// Also leaves the given words lowercase.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    /// use convert_case::pattern::{StyleGuide, TitleCase};
+    ///
+    /// let conv = Converter::new()
+    ///     .from_case(Case::Lower)
+    ///     .to_case(Case::Title)
+    ///     .set_pattern(TitleCase::new(StyleGuide::Ap).add_small_words(&["vs"]));
+    /// assert_eq!("Cats vs Dogs", conv.convert("cats vs dogs"));
+    /// ```
+    pub fn add_small_words<T: AsRef<str>>(mut self, words: &[T]) -> Self {
+        self.small_words
+            .extend(words.iter().map(|w| Cow::Owned(w.as_ref().to_lowercase())));
+        self
+    }
+
+    /// The words that are left lowercase.
+    pub fn small_words(&self) -> &[Cow<'static, str>] {
+        &self.small_words
+    }
+
+    fn is_small(&self, word: &str) -> bool {
+        let lower = word.to_lowercase();
+        self.small_words.iter().any(|w| *w == lower)
+    }
+
+    fn title(&self, word: &str, first: bool, last: bool) -> String {
+        let last_part = word.split('-').count() - 1;
+        word.split('-')
+            .enumerate()
+            .map(|(i, part)| {
+                let always_capital = (first && i == 0) || (last && i == last_part);
+                if !always_capital && self.is_small(part) {
+                    word_pattern::lowercase(part)
+                } else {
+                    word_pattern::capital(part)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+impl Pattern for TitleCase {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        let last = words.len().saturating_sub(1);
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| self.title(word, i == 0, i == last))
+            .collect()
+    }
+}
+
+impl From<StyleGuide> for TitleCase {
+    fn from(guide: StyleGuide) -> Self {
+        TitleCase::new(guide)
+    }
+}
+
+impl Default for TitleCase {
+    /// Follows the [Chicago](StyleGuide::Chicago) style guide.
+    fn default() -> Self {
+        TitleCase::new(StyleGuide::Chicago)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn title(guide: StyleGuide, words: &[&str]) -> String {
+        TitleCase::new(guide).mutate(words).join(" ")
+    }
+
+    #[test]
+    fn first_and_last_capitalized() {
+        assert_eq!(
+            "The Cat in the Hat",
+            title(StyleGuide::Chicago, &["the", "cat", "in", "the", "hat"])
+        );
+        assert_eq!(
+            "What to Look For",
+            title(StyleGuide::Ap, &["what", "to", "look", "for"])
+        );
+    }
+
+    #[test]
+    fn guides_differ_on_long_prepositions() {
+        let words = ["a", "walk", "along", "the", "river"];
+        assert_eq!("A Walk along the River", title(StyleGuide::Chicago, &words));
+        assert_eq!("A Walk Along the River", title(StyleGuide::Ap, &words));
+        assert_eq!("A Walk Along the River", title(StyleGuide::Apa, &words));
+    }
+
+    #[test]
+    fn hyphenated_compounds() {
+        assert_eq!(
+            "Self-Driving Cars",
+            title(StyleGuide::Ap, &["self-driving", "cars"])
+        );
+        assert_eq!(
+            "A Run-of-the-Mill Day",
+            title(StyleGuide::Chicago, &["a", "run-of-the-mill", "day"])
+        );
+        assert_eq!(
+            "Time to Check-In",
+            title(StyleGuide::Chicago, &["time", "to", "check-in"])
+        );
+        assert_eq!(
+            "In-Depth Look",
+            title(StyleGuide::Chicago, &["in-depth", "look"])
+        );
+    }
+
+    #[test]
+    fn small_words_ignore_case() {
+        assert_eq!(
+            "War and Peace",
+            title(StyleGuide::Chicago, &["WAR", "AND", "PEACE"])
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert!(TitleCase::default().mutate(&[]).is_empty());
+    }
+
+    #[test]
+    fn small_word_lists_are_sorted() {
+        for list in [
+            AP_SMALL_WORDS,
+            APA_SMALL_WORDS,
+            CHICAGO_SMALL_WORDS,
+            FRENCH_SMALL_WORDS,
+            GERMAN_SMALL_WORDS,
+            SPANISH_SMALL_WORDS,
+        ] {
+            assert!(list.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
@@ -0,0 +1,734 @@
+//! Converting strings into valid identifiers for a programming language.
+//!
+//! A case conversion alone does not always produce a usable identifier.  Converting
+//! `"type"` into snake case yields a Rust keyword, and `"2fa code"` yields an identifier that
+//! begins with a digit.  A [`Language`] converts into its conventional case and then escapes
+//! reserved words, leading digits and characters that cannot appear in an identifier.
+//! ```
+//! use convert_case::sanitize::Language;
+//!
+//! assert_eq!("r#type", Language::Rust.sanitize("type"));
+//! assert_eq!("type_", Language::Go.sanitize("type"));
+//! assert_eq!("_class", Language::JavaScript.sanitize("class"));
+//! assert_eq!("_2_fa_code", Language::Python.sanitize("2fa code"));
+//! assert_eq!("userName", Language::Java.sanitize("user.name"));
+//! ```
+
+use crate::boundary::{Boundary, Condition};
+use crate::{Case, Converter};
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+/// A programming language whose identifiers can be sanitized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Escapes keywords as raw identifiers, like `r#type`.  Keywords that cannot
+    /// be raw identifiers, like `self`, are followed by an underscore.
+    Rust,
+    /// Follows keywords with an underscore, like `class_`.
+    Python,
+    /// Also used for TypeScript.  Precedes keywords with an underscore, like `_class`.
+    JavaScript,
+    /// Follows keywords with an underscore, like `type_`.
+    Go,
+    /// Follows keywords with an underscore, like `class_`.
+    Java,
+    /// Quotes keywords with backticks, like `` `fun` ``.
+    Kotlin,
+    /// Precedes keywords with an at sign, like `@class`.
+    CSharp,
+    /// Follows keywords with an underscore, like `struct_`.
+    C,
+    /// Follows reserved words with an underscore, like `order_`.  Reserved words
+    /// are matched ignoring case.
+    Sql,
+}
+
+impl Language {
+    /// All the supported languages.
+    pub const fn all() -> &'static [Language] {
+        use Language::*;
+        &[Rust, Python, JavaScript, Go, Java, Kotlin, CSharp, C, Sql]
+    }
+
+    /// The case conventionally used for variable and function names.
+    ///
+    /// | Languages | Case |
+    /// | --- | --- |
+    /// | Rust, Python, C, Sql | [Snake](Case::Snake) |
+    /// | JavaScript, Go, Java, Kotlin, CSharp | [Camel](Case::Camel) |
+    pub const fn case(self) -> Case<'static> {
+        use Language::*;
+        match self {
+            Rust | Python | C | Sql => Case::Snake,
+            JavaScript | Go | Java | Kotlin | CSharp => Case::Camel,
+        }
+    }
+
+    /// The reserved words of the language, which cannot be used as identifiers.
+    pub const fn keywords(self) -> &'static [&'static str] {
+        use Language::*;
+        match self {
+            Rust => RUST_KEYWORDS,
+            Python => PYTHON_KEYWORDS,
+            JavaScript => JAVASCRIPT_KEYWORDS,
+            Go => GO_KEYWORDS,
+            Java => JAVA_KEYWORDS,
+            Kotlin => KOTLIN_KEYWORDS,
+            CSharp => CSHARP_KEYWORDS,
+            C => C_KEYWORDS,
+            Sql => SQL_KEYWORDS,
+        }
+    }
+
+    /// Whether `ident` is a reserved word of the language.
+    /// ```
+    /// use convert_case::sanitize::Language;
+    ///
+    /// assert!(Language::Rust.is_keyword("fn"));
+    /// assert!(!Language::Python.is_keyword("fn"));
+    /// assert!(Language::Sql.is_keyword("SELECT"));
+    /// ```
+    pub fn is_keyword(self, ident: &str) -> bool {
+        let mut keywords = self.keywords().iter();
+        match self {
+            Language::Sql => keywords.any(|k| k.eq_ignore_ascii_case(ident)),
+            _ => keywords.any(|k| *k == ident),
+        }
+    }
+
+    /// Converts `s` into the [conventional case](Language::case) of the language
+    /// and [escapes](Language::escape) the result.  Characters that cannot appear in
+    /// an identifier are treated as boundaries between words, so they are dropped
+    /// rather than replaced with underscores as in [`escape`](Language::escape).
+    /// Letters the language does not allow are dropped too, and inputs that differ
+    /// only in those letters give the same identifier.
+    /// ```
+    /// use convert_case::sanitize::Language;
+    ///
+    /// assert_eq!("self_", Language::Rust.sanitize("Self"));
+    /// assert_eq!("order_id", Language::Sql.sanitize("Order #ID"));
+    /// assert_eq!("_default", Language::JavaScript.sanitize("default"));
+    ///
+    /// // Letters outside ASCII cannot appear in C identifiers
+    /// assert_eq!("caf_name", Language::C.sanitize("café name"));
+    /// assert_eq!("caf__name", Language::C.escape("café_name"));
+    /// ```
+    pub fn sanitize<T: AsRef<str>>(self, s: T) -> String {
+        self.sanitize_as(s, self.case())
+    }
+
+    /// Converts `s` into the given case and [escapes](Language::escape) the result.
+    /// Characters that cannot appear in an identifier are treated as boundaries between
+    /// words and dropped, as in [`sanitize`](Language::sanitize).
+    /// ```
+    /// use convert_case::{Case, sanitize::Language};
+    ///
+    /// assert_eq!("MAX_SIZE", Language::Rust.sanitize_as("max-size", Case::Constant));
+    /// assert_eq!("Self_", Language::Rust.sanitize_as("self", Case::Pascal));
+    /// ```
+    pub fn sanitize_as<T: AsRef<str>>(self, s: T, case: Case) -> String {
+        let conv = Converter::new().add_boundary(self.illegal_boundary());
+        self.escape(conv.as_borrowed().to_case(case).convert(s))
+    }
+
+    /// Makes `ident` a valid identifier without changing its case.  Characters that cannot
+    /// appear in an identifier are replaced with underscores, an identifier that cannot
+    /// begin with its first character, such as a digit, is preceded by an underscore, and keywords are escaped in the manner
+    /// of the language.  An empty identifier becomes an underscore.
+    /// ```
+    /// use convert_case::sanitize::Language;
+    ///
+    /// assert_eq!("r#match", Language::Rust.escape("match"));
+    /// assert_eq!("class_", Language::Python.escape("class"));
+    /// assert_eq!("_3d_model", Language::C.escape("3d-model"));
+    /// assert_eq!("_", Language::Go.escape(""));
+    /// ```
+    pub fn escape<T: AsRef<str>>(self, ident: T) -> String {
+        let legal = self.legal_char();
+        let mut ident: String = ident
+            .as_ref()
+            .chars()
+            .map(|c| if legal(c) { c } else { '_' })
+            .collect();
+
+        if ident.chars().next().is_none_or(|c| !self.legal_start(c)) {
+            ident.insert(0, '_');
+        }
+
+        if !self.is_keyword(&ident) {
+            ident
+        } else if self == Language::JavaScript {
+            format!("_{}", ident)
+        } else if self == Language::Kotlin {
+            format!("`{}`", ident)
+        } else if self == Language::CSharp {
+            format!("@{}", ident)
+        } else if self == Language::Rust && !RUST_NOT_RAW.contains(&ident.as_str()) {
+            format!("r#{}", ident)
+        } else {
+            ident.push('_');
+            ident
+        }
+    }
+
+    /// Characters that can appear in an identifier.  Unicode identifiers follow
+    /// `XID_Continue`, so combining marks are kept and characters like `²` are not.
+    fn legal_char(self) -> fn(char) -> bool {
+        use Language::*;
+        match self {
+            Rust | Python | Go | Kotlin | CSharp => is_xid_continue,
+            JavaScript | Java => |c| is_xid_continue(c) || c == '$',
+            C | Sql => |c| c.is_ascii_alphanumeric() || c == '_',
+        }
+    }
+
+    /// Whether an identifier can begin with `c`.
+    fn legal_start(self, c: char) -> bool {
+        use Language::*;
+        match self {
+            Rust | Python | Go | Kotlin | CSharp => is_xid_start(c) || c == '_',
+            JavaScript | Java => is_xid_start(c) || c == '_' || c == '$',
+            C | Sql => c.is_ascii_alphabetic() || c == '_',
+        }
+    }
+
+    pub(crate) fn illegal_boundary(self) -> Boundary {
+        use Language::*;
+        match self {
+            Rust | Python | Go | Kotlin | CSharp => ILLEGAL_UNICODE,
+            JavaScript | Java => ILLEGAL_UNICODE_DOLLAR,
+            C | Sql => ILLEGAL_ASCII,
+        }
+    }
+}
+
+// A grapheme is illegal when its first character is, so the combining marks that
+// follow a legal character are kept and escaped on their own
+
+const ILLEGAL_UNICODE: Boundary = Boundary {
+    name: Cow::Borrowed("IllegalUnicode"),
+    condition: Condition::Fn(|s| first_char(s).is_some_and(|c| !is_xid_continue(c))),
+    start: 0,
+    len: 1,
+};
+
+const ILLEGAL_UNICODE_DOLLAR: Boundary = Boundary {
+    name: Cow::Borrowed("IllegalUnicodeDollar"),
+    condition: Condition::Fn(|s| first_char(s).is_some_and(|c| !is_xid_continue(c) && c != '$')),
+    start: 0,
+    len: 1,
+};
+
+const ILLEGAL_ASCII: Boundary = Boundary {
+    name: Cow::Borrowed("IllegalAscii"),
+    condition: Condition::Fn(|s| {
+        first_char(s).is_some_and(|c| !c.is_ascii_alphanumeric() && c != '_')
+    }),
+    start: 0,
+    len: 1,
+};
+
+fn first_char(graphemes: &[&str]) -> Option<char> {
+    graphemes.first().and_then(|g| g.chars().next())
+}
+
+/// Rust keywords that cannot be written as raw identifiers.
+const RUST_NOT_RAW: &[&str] = &["Self", "_", "crate", "self", "super"];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+const JAVA_KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+const C_KEYWORDS: &[&str] = &[
+    "_Alignas",
+    "_Alignof",
+    "_Atomic",
+    "_BitInt",
+    "_Bool",
+    "_Complex",
+    "_Decimal128",
+    "_Decimal32",
+    "_Decimal64",
+    "_Generic",
+    "_Imaginary",
+    "_Noreturn",
+    "_Static_assert",
+    "_Thread_local",
+    "alignas",
+    "alignof",
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "nullptr",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "struct",
+    "switch",
+    "thread_local",
+    "true",
+    "typedef",
+    "typeof",
+    "typeof_unqual",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+];
+
+const SQL_KEYWORDS: &[&str] = &[
+    "add",
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "cast",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "database",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "revoke",
+    "right",
+    "row",
+    "rows",
+    "select",
+    "set",
+    "some",
+    "table",
+    "then",
+    "to",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "view",
+    "when",
+    "where",
+    "with",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keyword_escapes() {
+        assert_eq!("r#type", Language::Rust.sanitize("type"));
+        assert_eq!("self_", Language::Rust.sanitize("self"));
+        assert_eq!("class_", Language::Python.sanitize("class"));
+        assert_eq!("_function", Language::JavaScript.sanitize("function"));
+        assert_eq!("func_", Language::Go.sanitize("func"));
+        assert_eq!("public_", Language::Java.sanitize("public"));
+        assert_eq!("`fun`", Language::Kotlin.sanitize("fun"));
+        assert_eq!("@class", Language::CSharp.sanitize("class"));
+        assert_eq!("struct_", Language::C.sanitize("struct"));
+        assert_eq!("select_", Language::Sql.sanitize("Select"));
+    }
+
+    #[test]
+    fn leading_digits() {
+        for &lang in Language::all() {
+            assert!(lang.sanitize("2fa code").starts_with("_2"), "{:?}", lang);
+        }
+    }
+
+    #[test]
+    fn illegal_characters_split_words() {
+        assert_eq!("user_email", Language::Rust.sanitize("user.email"));
+        assert_eq!("$userEmail", Language::JavaScript.sanitize("$user email"));
+        assert_eq!("userEmail", Language::Go.sanitize("user$email"));
+        assert_eq!("caf_name", Language::C.sanitize("café name"));
+        assert_eq!("café_name", Language::Python.sanitize("café name"));
+
+        // Combining marks are kept with the letter before them
+        assert_eq!(
+            "cafe\u{301}_name",
+            Language::Python.sanitize("cafe\u{301} name")
+        );
+        assert_eq!("cafe\u{301}", Language::Rust.escape("cafe\u{301}"));
+        assert_eq!("_\u{301}e", Language::Rust.escape("\u{301}e"));
+
+        // Superscript digits are alphanumeric but not identifier characters
+        assert_eq!("x_y", Language::Rust.sanitize("x² y"));
+        assert_eq!("x_", Language::Rust.escape("x²"));
+    }
+
+    #[test]
+    fn illegal_characters_dropped_when_splitting() {
+        assert_eq!("caf_name", Language::C.sanitize("café name"));
+        assert_eq!("caf_name", Language::C.sanitize("cafè name"));
+        assert_eq!("caf__name", Language::C.escape("café_name"));
+        assert_eq!("user_email", Language::Sql.sanitize("user.email"));
+        assert_eq!("user_email", Language::Sql.escape("user.email"));
+    }
+
+    #[test]
+    fn keywords_are_sorted_and_unique() {
+        for &lang in Language::all() {
+            let keywords = lang.keywords();
+            assert!(
+                keywords.windows(2).all(|w| w[0] < w[1]),
+                "{:?} keywords",
+                lang
+            );
+        }
+    }
+
+    #[test]
+    fn sanitized_are_not_keywords() {
+        for &lang in Language::all() {
+            for keyword in lang.keywords() {
+                let ident = lang.escape(keyword);
+                assert!(!lang.is_keyword(&ident), "{:?} in {:?}", ident, lang);
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("_", Language::Python.sanitize(""));
+        assert_eq!("__", Language::Rust.sanitize(""));
+    }
+}
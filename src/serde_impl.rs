@@ -0,0 +1,581 @@
+//! Implementations of `Serialize` and `Deserialize` for the `serde` feature.
+//!
+//! Only values that can be named are serializable: built-in cases, boundaries and patterns,
+//! [`TitleCase`] patterns, and boundaries created by [`Boundary::from_delim`] or
+//! [`Boundary::from_owned_delim`].  Anything defined by a custom
+//! function pointer returns an error instead.
+
+use crate::pattern::{self, Capitalize, Pattern, TitleCase};
+use crate::{
+    AcronymStyle, Boundary, Case, CaseRegistry, CaseSpec, Condition, Converter, Locale,
+    DEFAULT_INITIALISMS,
+};
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::any::Any;
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Cases are serialized by name and deserialized using [`FromStr`](core::str::FromStr),
+/// so aliases like `"kebab-case"` are accepted.
+impl Serialize for Case<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Case::Custom { .. } = self {
+            return Err(ser::Error::custom(
+                "Case::Custom cannot be serialized, only built-in cases are supported",
+            ));
+        }
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Case<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Built-in boundaries are written as their name, and delimiter boundaries
+/// as a map with a single `delim` field.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum BoundaryRepr<S> {
+    Name(S),
+    Delim { delim: S },
+}
+
+impl Serialize for Boundary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = if let Condition::Delim(delim) = &self.condition {
+            BoundaryRepr::Delim { delim: &**delim }
+        } else if is_builtin(self) {
+            BoundaryRepr::Name(&*self.name)
+        } else {
+            return Err(ser::Error::custom(format!(
+                "boundary {:?} has a custom condition and cannot be serialized",
+                self.name
+            )));
+        };
+        repr.serialize(serializer)
+    }
+}
+
+/// Whether `b` is a built-in boundary, comparing its condition and not only its name,
+/// since a custom boundary may share the name of a built-in.
+fn is_builtin(b: &Boundary) -> bool {
+    let Condition::Fn(f) = b.condition else {
+        return false;
+    };
+    Boundary::BUILTINS.iter().any(|builtin| {
+        matches!(builtin.condition, Condition::Fn(g) if g as usize == f as usize)
+            && builtin.name == b.name
+            && builtin.start == b.start
+            && builtin.len == b.len
+    })
+}
+
+impl<'de> Deserialize<'de> for Boundary {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match BoundaryRepr::<String>::deserialize(deserializer)? {
+            BoundaryRepr::Name(name) => Boundary::BUILTINS
+                .into_iter()
+                .find(|b| b.name == name)
+                .ok_or_else(|| de::Error::custom(format!("unknown boundary {:?}", name))),
+            BoundaryRepr::Delim { delim } => Ok(Boundary::from_owned_delim(delim)),
+        }
+    }
+}
+
+/// Acronym styles are written in snake case, such as `"two_letter_upper"`.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AcronymStyle", rename_all = "snake_case")]
+enum AcronymStyleDef {
+    Capitalized,
+    Upper,
+    TwoLetterUpper,
+}
+
+impl Serialize for AcronymStyle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AcronymStyleDef::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AcronymStyle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        AcronymStyleDef::deserialize(deserializer)
+    }
+}
+
+/// Locales are written in snake case, such as `"turkish"`.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Locale", rename_all = "snake_case")]
+enum LocaleDef {
+    Root,
+    Turkish,
+    Azeri,
+    Dutch,
+    German,
+    Greek,
+    Lithuanian,
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LocaleDef::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LocaleDef::deserialize(deserializer)
+    }
+}
+
+/// Capitalization positions are written in snake case, such as `"first_letter"`.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Capitalize", rename_all = "snake_case")]
+enum CapitalizeDef {
+    FirstGrapheme,
+    FirstLetter,
+}
+
+impl Serialize for Capitalize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CapitalizeDef::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Capitalize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CapitalizeDef::deserialize(deserializer)
+    }
+}
+
+/// Title case patterns are written as a map with their `small_words`.
+impl Serialize for TitleCase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TitleCaseRepr {
+            small_words: self.small_words(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TitleCase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = TitleCaseRepr::<Vec<String>>::deserialize(deserializer)?;
+        Ok(TitleCase::from_small_words(&repr.small_words))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TitleCaseRepr<W> {
+    small_words: W,
+}
+
+/// Patterns are written as the name of a function in the [`pattern`] module,
+/// or as a map with a single `title` field holding a [`TitleCase`].
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum PatternRepr<S, T> {
+    Name(S),
+    Title { title: T },
+}
+
+fn pattern_repr<E: ser::Error>(
+    p: &dyn Pattern,
+) -> Result<PatternRepr<&'static str, &TitleCase>, E> {
+    let inner = pattern::inner(p);
+    if let Some(title) = (inner as &dyn Any).downcast_ref::<TitleCase>() {
+        return Ok(PatternRepr::Title { title });
+    }
+    pattern::NAMED
+        .iter()
+        .find(|(_, named)| pattern::same(*named, p))
+        .map(|(name, _)| PatternRepr::Name(*name))
+        .ok_or_else(|| ser::Error::custom("custom pattern functions cannot be serialized"))
+}
+
+fn repr_pattern<E: de::Error>(repr: PatternRepr<String, TitleCase>) -> Result<Box<dyn Pattern>, E> {
+    let name = match repr {
+        PatternRepr::Name(name) => name,
+        PatternRepr::Title { title } => return Ok(Box::new(title)),
+    };
+    pattern::NAMED
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, p)| Box::new(*p) as Box<dyn Pattern>)
+        .ok_or_else(|| de::Error::custom(format!("unknown pattern {:?}", name)))
+}
+
+#[derive(Serialize)]
+struct ConverterRef<'a> {
+    boundaries: &'a [Boundary],
+    lexicon: &'a [Cow<'static, str>],
+    pattern: PatternRepr<&'static str, &'a TitleCase>,
+    initialisms: &'a [Cow<'static, str>],
+    acronym_style: AcronymStyle,
+    locale: Locale,
+    capitalize: Capitalize,
+    #[cfg(feature = "random")]
+    seed: Option<u64>,
+    delim: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct ConverterRepr {
+    boundaries: Vec<Boundary>,
+    lexicon: Vec<Cow<'static, str>>,
+    pattern: PatternRepr<String, TitleCase>,
+    initialisms: Vec<Cow<'static, str>>,
+    acronym_style: AcronymStyle,
+    locale: Locale,
+    capitalize: Capitalize,
+    #[cfg(feature = "random")]
+    seed: Option<u64>,
+    delim: String,
+}
+
+impl Default for ConverterRepr {
+    fn default() -> Self {
+        ConverterRepr {
+            boundaries: Boundary::defaults().to_vec(),
+            lexicon: Vec::new(),
+            pattern: noop_name(),
+            initialisms: DEFAULT_INITIALISMS
+                .iter()
+                .map(|&w| Cow::Borrowed(w))
+                .collect(),
+            acronym_style: AcronymStyle::Capitalized,
+            locale: Locale::Root,
+            capitalize: Capitalize::FirstGrapheme,
+            #[cfg(feature = "random")]
+            seed: None,
+            delim: String::new(),
+        }
+    }
+}
+
+/// Converters are serialized as a map of their fields, with the pattern
+/// written as the name of a function in the [`pattern`] module or as a [`TitleCase`].  Missing fields
+/// are deserialized to those of [`Converter::new`].
+impl Serialize for Converter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let pattern = pattern_repr(&*self.pattern)?;
+        ConverterRef {
+            boundaries: &self.boundaries,
+            lexicon: &self.lexicon,
+            pattern,
+            initialisms: &self.initialisms,
+            acronym_style: self.acronym_style,
+            locale: self.locale,
+            capitalize: self.capitalize,
+            #[cfg(feature = "random")]
+            seed: self.seed,
+            delim: &self.delim,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Converter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ConverterRepr::deserialize(deserializer)?;
+        let pattern = repr_pattern::<D::Error>(repr.pattern)?;
+        Ok(Converter {
+            boundaries: repr.boundaries,
+            lexicon: repr.lexicon,
+            pattern,
+            initialisms: repr.initialisms,
+            acronym_style: repr.acronym_style,
+            locale: repr.locale,
+            capitalize: repr.capitalize,
+            #[cfg(feature = "random")]
+            seed: repr.seed,
+            delim: repr.delim,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct CaseSpecRef<'a> {
+    name: &'a str,
+    boundaries: &'a [Boundary],
+    pattern: PatternRepr<&'static str, &'a TitleCase>,
+    delim: &'a str,
+}
+
+#[derive(Deserialize)]
+struct CaseSpecRepr {
+    name: String,
+    #[serde(default)]
+    boundaries: Vec<Boundary>,
+    #[serde(default = "noop_name")]
+    pattern: PatternRepr<String, TitleCase>,
+    #[serde(default)]
+    delim: String,
+}
+
+fn noop_name() -> PatternRepr<String, TitleCase> {
+    PatternRepr::Name(String::from("noop"))
+}
+
+/// Case specs are serialized as a map of their name, boundaries, pattern and delimiter,
+/// with the pattern written as the name of a function in the [`pattern`] module or as a
+/// [`TitleCase`].  Missing
+/// fields are deserialized to those of [`CaseSpec::new`].
+impl Serialize for CaseSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let pattern = pattern_repr(self.pattern())?;
+        CaseSpecRef {
+            name: self.name(),
+            boundaries: self.boundaries(),
+            pattern,
+            delim: self.delim(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CaseSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CaseSpecRepr::deserialize(deserializer)?;
+        Ok(CaseSpec::new(repr.name)
+            .set_boundaries(&repr.boundaries)
+            .set_pattern(repr_pattern::<D::Error>(repr.pattern)?)
+            .set_delim(repr.delim))
+    }
+}
+
+/// Registries are serialized as a sequence of their case specs.  When deserializing,
+/// a later spec replaces an earlier one with the same name.
+impl Serialize for CaseRegistry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.specs().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CaseRegistry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<CaseSpec>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Casing;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn case_round_trip() {
+        for &case in Case::all_cases() {
+            let json = serde_json::to_string(&case).unwrap();
+            assert_eq!(case, serde_json::from_str::<Case>(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn case_aliases() {
+        assert_eq!(
+            Case::Kebab,
+            serde_json::from_str::<Case>("\"kebab-case\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_case_errors() {
+        let custom = Case::Custom {
+            boundaries: &[],
+            pattern: &pattern::lowercase,
+            delim: ".",
+        };
+        let err = serde_json::to_string(&custom).unwrap_err();
+        assert!(err.to_string().contains("Case::Custom"));
+    }
+
+    #[test]
+    fn builtin_boundaries() {
+        for b in Boundary::BUILTINS {
+            let json = serde_json::to_string(&b).unwrap();
+            assert_eq!(format!("\"{}\"", b.name), json);
+            assert_eq!(b, serde_json::from_str::<Boundary>(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn delim_boundary() {
+        let json = serde_json::to_string(&Boundary::from_delim("::")).unwrap();
+        assert_eq!(r#"{"delim":"::"}"#, json);
+        let b: Boundary = serde_json::from_str(&json).unwrap();
+        assert_eq!(vec!["a", "b"], crate::split(&"a::b", &[b]));
+    }
+
+    #[test]
+    fn custom_boundary_errors() {
+        let b = Boundary {
+            name: "Custom".into(),
+            condition: Condition::Fn(|_| false),
+            start: 0,
+            len: 0,
+        };
+        assert!(serde_json::to_string(&b).is_err());
+    }
+
+    #[test]
+    fn custom_boundary_with_builtin_name_errors() {
+        let b = Boundary {
+            name: "Underscore".into(),
+            condition: Condition::Fn(|_| false),
+            start: 0,
+            len: 1,
+        };
+        let err = serde_json::to_string(&b).unwrap_err();
+        assert!(err.to_string().contains("custom condition"));
+
+        let b = Boundary::from_condition("Underscore", |_: &[&str]| false, 0, 1);
+        assert!(serde_json::to_string(&b).is_err());
+    }
+
+    #[test]
+    fn converter_round_trip() {
+        let conv = Converter::new().from_case(Case::Camel).to_case(Case::Snake);
+        let json = serde_json::to_string(&conv).unwrap();
+        let conv: Converter = serde_json::from_str(&json).unwrap();
+        assert_eq!("my_var_name", conv.convert("myVarName"));
+    }
+
+    #[test]
+    fn converter_defaults() {
+        let conv: Converter = serde_json::from_str(r#"{"pattern":"uppercase"}"#).unwrap();
+        assert_eq!("MYVARNAME", conv.convert("my-var name"));
+    }
+
+    #[test]
+    fn converter_acronym_style() {
+        let conv: Converter =
+            serde_json::from_str(r#"{"pattern":"capital","acronym_style":"two_letter_upper"}"#)
+                .unwrap();
+        assert_eq!("IOXml", conv.convert("io_xml"));
+        let json = serde_json::to_string(&conv).unwrap();
+        assert!(json.contains(r#""acronym_style":"two_letter_upper""#));
+    }
+
+    #[test]
+    fn converter_locale() {
+        let conv: Converter =
+            serde_json::from_str(r#"{"pattern":"uppercase","locale":"turkish"}"#).unwrap();
+        assert_eq!("İZMİR", conv.convert("izmir"));
+        let json = serde_json::to_string(&conv).unwrap();
+        assert!(json.contains(r#""locale":"turkish""#));
+    }
+
+    #[test]
+    fn converter_capitalize() {
+        let conv: Converter =
+            serde_json::from_str(r#"{"pattern":"capital","capitalize":"first_letter"}"#).unwrap();
+        assert_eq!("'Tis", conv.convert("'tis"));
+        let json = serde_json::to_string(&conv).unwrap();
+        assert!(json.contains(r#""capitalize":"first_letter""#));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn converter_seed() {
+        let conv: Converter = serde_json::from_str(r#"{"pattern":"random","seed":7}"#).unwrap();
+        assert_eq!(Some(7), conv.seed);
+        let json = serde_json::to_string(&conv).unwrap();
+        assert!(json.contains(r#""seed":7"#));
+    }
+
+    #[test]
+    fn converter_from_custom_case() {
+        let dot_case = Case::Custom {
+            boundaries: &[],
+            pattern: &pattern::uppercase,
+            delim: ".",
+        };
+        let json = serde_json::to_string(&Converter::new().to_case(dot_case)).unwrap();
+        assert!(json.contains(r#""pattern":"uppercase""#));
+    }
+
+    #[test]
+    fn converter_custom_pattern_errors() {
+        let conv = Converter::new()
+            .set_pattern(|words: &[&str]| words.iter().map(|w| w.to_string()).collect());
+        assert!(serde_json::to_string(&conv).is_err());
+    }
+
+    #[test]
+    fn converter_title_case() {
+        let conv = Converter::new()
+            .from_case(Case::Lower)
+            .to_case(Case::Title)
+            .set_pattern(TitleCase::from_small_words(&["of", "the"]));
+        let json = serde_json::to_string(&conv).unwrap();
+        assert!(json.contains(r#""pattern":{"title":{"small_words":["of","the"]}}"#));
+        let conv: Converter = serde_json::from_str(&json).unwrap();
+        assert_eq!("Lord of the Rings", conv.convert("lord of the rings"));
+
+        let spec = CaseSpec::new("headline")
+            .set_pattern(TitleCase::new(pattern::StyleGuide::Chicago))
+            .set_delim(" ");
+        let json = serde_json::to_string(&spec).unwrap();
+        let spec: CaseSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            "The Cat in the Hat",
+            spec.converter()
+                .from_case(Case::Lower)
+                .convert("the cat in the hat")
+        );
+    }
+
+    #[test]
+    fn case_spec_round_trip() {
+        let spec = CaseSpec::new("env")
+            .add_boundary(Boundary::from_delim("__"))
+            .set_pattern(pattern::uppercase)
+            .set_delim("__");
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(
+            r#"{"name":"env","boundaries":[{"delim":"__"}],"pattern":"uppercase","delim":"__"}"#,
+            json
+        );
+        assert_eq!(spec, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn case_spec_defaults() {
+        let spec: CaseSpec = serde_json::from_str(r#"{"name":"verbatim"}"#).unwrap();
+        assert_eq!(CaseSpec::new("verbatim"), spec);
+        assert!(serde_json::from_str::<CaseSpec>(r#"{"pattern":"camel"}"#).is_err());
+        assert!(serde_json::from_str::<CaseSpec>(r#"{"name":"x","pattern":"shout"}"#).is_err());
+    }
+
+    #[test]
+    fn registry_from_config() {
+        let registry: CaseRegistry = serde_json::from_str(
+            r#"[
+                {"name": "column", "boundaries": ["Underscore"], "pattern": "lowercase", "delim": "_"},
+                {"name": "env", "boundaries": [{"delim": "__"}], "pattern": "uppercase", "delim": "__"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(2, registry.specs().len());
+        let env = registry.parse("env").unwrap();
+        assert_eq!("APP__DATABASE__URL", "app databaseUrl".to_case(env));
+        let json = serde_json::to_string(&registry).unwrap();
+        assert_eq!(registry, serde_json::from_str(&json).unwrap());
+    }
+}
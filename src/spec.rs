@@ -0,0 +1,446 @@
+use crate::case::normalize_name;
+use crate::detect::{self, Detection};
+use crate::pattern::{self, Pattern};
+use crate::{Boundary, Case, Converter, ParseCaseError};
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// An owned description of a case, which can be created at runtime.
+///
+/// A [`Case::Custom`] borrows its boundaries, pattern and delimiter, so it cannot outlive
+/// the data it was built from.  A `CaseSpec` owns its name, boundaries, pattern and
+/// delimiter, so it can be built from a configuration file and kept for as long as it
+/// is needed.  The pattern is shared between clones of the spec.
+/// Use [`as_case`](CaseSpec::as_case) wherever a [`Case`] is expected.
+/// ```
+/// use convert_case::{pattern, Boundary, CaseSpec, Casing};
+///
+/// let delim = String::from("__");
+/// let env = CaseSpec::new("env")
+///     .set_boundaries(&[Boundary::from_owned_delim(delim.clone())])
+///     .set_pattern(pattern::uppercase)
+///     .set_delim(delim);
+///
+/// assert_eq!("DATABASE__URL", "databaseUrl".to_case(env.as_case()));
+/// assert_eq!("DATABASE__URL", env.converter().convert("database url"));
+/// assert_eq!(
+///     "database.url",
+///     "DATABASE__URL".from_case(env.as_case()).to_case(convert_case::Case::Dot)
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CaseSpec {
+    name: String,
+    boundaries: Vec<Boundary>,
+    pattern: Arc<dyn Pattern>,
+    delim: String,
+}
+
+impl CaseSpec {
+    /// Creates a case with the given name, no boundaries, the [`noop`](pattern::noop)
+    /// pattern and an empty delimiter.
+    pub fn new<T: ToString>(name: T) -> Self {
+        CaseSpec {
+            name: name.to_string(),
+            boundaries: Vec::new(),
+            pattern: Arc::new(pattern::noop),
+            delim: String::new(),
+        }
+    }
+
+    /// Creates a case with the given name and the boundaries, pattern and delimiter
+    /// of an existing case.
+    /// ```
+    /// use convert_case::{Case, CaseSpec};
+    ///
+    /// let column = CaseSpec::from_case("column", Case::Snake);
+    /// assert_eq!("_", column.delim());
+    /// assert_eq!(Case::Snake.boundaries(), column.boundaries());
+    /// ```
+    pub fn from_case<T: ToString>(name: T, case: Case<'static>) -> Self {
+        CaseSpec {
+            name: name.to_string(),
+            boundaries: case.boundaries().to_vec(),
+            pattern: Arc::new(case.pattern()),
+            delim: case.delim().to_string(),
+        }
+    }
+
+    /// Sets the boundaries used to split an identifier in this case into words.
+    pub fn set_boundaries(mut self, bs: &[Boundary]) -> Self {
+        self.boundaries = bs.to_vec();
+        self
+    }
+
+    /// Adds a boundary used to split an identifier in this case into words.
+    pub fn add_boundary(mut self, b: Boundary) -> Self {
+        self.boundaries.push(b);
+        self
+    }
+
+    /// Sets the pattern that mutates each word.  The pattern can be configured at
+    /// runtime, since the spec owns it.
+    /// ```
+    /// use convert_case::{pattern, Boundary, CaseSpec, Casing};
+    ///
+    /// let prefix = String::from("x");
+    /// let header = CaseSpec::new("header")
+    ///     .set_boundaries(&[Boundary::SPACE])
+    ///     .set_pattern(move |words: &[&str]| {
+    ///         let mut words = pattern::lowercase(words);
+    ///         words.insert(0, prefix.clone());
+    ///         words
+    ///     })
+    ///     .set_delim("-");
+    ///
+    /// assert_eq!("x-request-id", "request id".to_case(header.as_case()));
+    /// ```
+    pub fn set_pattern<P: Pattern>(mut self, p: P) -> Self {
+        self.pattern = Arc::new(p);
+        self
+    }
+
+    /// Sets the delimiter that joins words.
+    pub fn set_delim<T: ToString>(mut self, d: T) -> Self {
+        self.delim = d.to_string();
+        self
+    }
+
+    /// The name of the case.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The boundaries used to split an identifier in this case into words.
+    pub fn boundaries(&self) -> &[Boundary] {
+        &self.boundaries
+    }
+
+    /// The pattern that mutates each word.
+    pub fn pattern(&self) -> &dyn Pattern {
+        &*self.pattern
+    }
+
+    /// The delimiter that joins words.
+    pub fn delim(&self) -> &str {
+        &self.delim
+    }
+
+    /// Borrows the case as a [`Case::Custom`].
+    pub fn as_case(&self) -> Case<'_> {
+        Case::Custom {
+            boundaries: &self.boundaries,
+            pattern: &*self.pattern,
+            delim: &self.delim,
+        }
+    }
+
+    /// A converter into this case.  Like [`Converter::to_case`], words are
+    /// split on the default boundaries.
+    pub fn converter(&self) -> Converter {
+        Converter::new()
+            .set_pattern(Arc::clone(&self.pattern))
+            .set_delim(&self.delim)
+    }
+}
+
+/// Specs are equal when they have the same name and describe equal [`Case::Custom`]s.
+impl PartialEq for CaseSpec {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.as_case() == other.as_case()
+    }
+}
+
+impl Eq for CaseSpec {}
+
+impl Hash for CaseSpec {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.as_case().hash(state);
+    }
+}
+
+impl fmt::Debug for CaseSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CaseSpec")
+            .field("name", &self.name)
+            .field("boundaries", &self.boundaries)
+            .field("delim", &self.delim)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Displays the name of the case.
+impl fmt::Display for CaseSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// A set of [`CaseSpec`]s that can be looked up by name alongside the built-in cases.
+///
+/// Names are matched the same way as by [`Case::from_str`](core::str::FromStr), ignoring
+/// capitalization, separators and a trailing "case".  A registered case takes precedence
+/// over a built-in case with the same name.
+/// ```
+/// use convert_case::{pattern, Case, CaseRegistry, CaseSpec};
+///
+/// let mut registry = CaseRegistry::new();
+/// registry.register(
+///     CaseSpec::new("Env")
+///         .add_boundary(convert_case::Boundary::from_delim("__"))
+///         .set_pattern(pattern::uppercase)
+///         .set_delim("__"),
+/// );
+///
+/// assert_eq!(Ok(Case::Kebab), registry.parse("kebab"));
+/// let env = registry.parse("env-case").unwrap();
+/// assert_eq!("__", env.delim());
+///
+/// let detection = registry.detect("DATABASE__URL");
+/// assert_eq!(Some(env), detection.best());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaseRegistry {
+    specs: Vec<CaseSpec>,
+}
+
+impl CaseRegistry {
+    /// Creates a registry without any cases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a case, returning the case it replaced if one was already
+    /// registered with the same name.
+    /// ```
+    /// use convert_case::{Case, CaseRegistry, CaseSpec};
+    ///
+    /// let mut registry = CaseRegistry::new();
+    /// assert_eq!(None, registry.register(CaseSpec::from_case("column", Case::Snake)));
+    ///
+    /// let replaced = registry.register(CaseSpec::from_case("Column", Case::Camel));
+    /// assert_eq!(Some("column"), replaced.as_ref().map(|spec| spec.name()));
+    /// assert_eq!(1, registry.specs().len());
+    /// ```
+    pub fn register(&mut self, spec: CaseSpec) -> Option<CaseSpec> {
+        let name = normalize_name(&spec.name);
+        match self
+            .specs
+            .iter_mut()
+            .find(|s| normalize_name(&s.name) == name)
+        {
+            Some(existing) => Some(core::mem::replace(existing, spec)),
+            None => {
+                self.specs.push(spec);
+                None
+            }
+        }
+    }
+
+    /// Removes the case registered with the given name.
+    pub fn unregister(&mut self, name: &str) -> Option<CaseSpec> {
+        let name = normalize_name(name);
+        let index = self
+            .specs
+            .iter()
+            .position(|s| normalize_name(&s.name) == name)?;
+        Some(self.specs.remove(index))
+    }
+
+    /// The case registered with the given name.  Built-in cases are not included.
+    pub fn spec(&self, name: &str) -> Option<&CaseSpec> {
+        let name = normalize_name(name);
+        self.specs.iter().find(|s| normalize_name(&s.name) == name)
+    }
+
+    /// The registered cases, in the order they were registered.
+    pub fn specs(&self) -> &[CaseSpec] {
+        &self.specs
+    }
+
+    /// Parses a case from the name of a registered case, or else the name or
+    /// alias of a built-in case.
+    /// ```
+    /// use convert_case::{Case, CaseRegistry, CaseSpec};
+    ///
+    /// let registry: CaseRegistry = [CaseSpec::from_case("column", Case::Snake)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(Ok(Case::Constant), registry.parse("SCREAMING_SNAKE"));
+    /// assert_eq!(Ok("_"), registry.parse("column").map(|case| case.delim()));
+    ///
+    /// let err = registry.parse("row").unwrap_err();
+    /// assert!(err.to_string().ends_with(", column"));
+    /// ```
+    pub fn parse(&self, name: &str) -> Result<Case<'_>, ParseCaseError> {
+        if let Some(spec) = self.spec(name) {
+            return Ok(spec.as_case());
+        }
+        name.parse().map_err(|mut err: ParseCaseError| {
+            err.registered = self.specs.iter().map(|s| s.name.clone()).collect();
+            err
+        })
+    }
+
+    /// Detects which cases `s` could be in, considering both the
+    /// [deterministic](Case::deterministic_cases) built-in cases and the registered cases.
+    /// See [`Detection`] for how candidates are chosen.
+    pub fn detect<T: AsRef<str>>(&self, s: T) -> Detection<'_> {
+        let mut cases: Vec<Case> = Case::deterministic_cases().to_vec();
+        cases.extend(self.specs.iter().map(CaseSpec::as_case));
+        detect::detect_among(s.as_ref(), cases)
+    }
+}
+
+impl Extend<CaseSpec> for CaseRegistry {
+    fn extend<I: IntoIterator<Item = CaseSpec>>(&mut self, iter: I) {
+        for spec in iter {
+            self.register(spec);
+        }
+    }
+}
+
+impl FromIterator<CaseSpec> for CaseRegistry {
+    fn from_iter<I: IntoIterator<Item = CaseSpec>>(iter: I) -> Self {
+        let mut registry = CaseRegistry::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::Casing;
+
+    fn dunder() -> CaseSpec {
+        CaseSpec::new("dunder")
+            .add_boundary(Boundary::from_owned_delim(String::from("__")))
+            .set_pattern(pattern::lowercase)
+            .set_delim(String::from("__"))
+    }
+
+    #[test]
+    fn spec_converts() {
+        let spec = dunder();
+        assert_eq!("my__var__name", "MyVarName".to_case(spec.as_case()));
+        assert_eq!("my__var__name", spec.converter().convert("my-var-name"));
+        assert_eq!(
+            "MyVarName",
+            "my__var__name"
+                .from_case(spec.as_case())
+                .to_case(Case::Pascal)
+        );
+    }
+
+    #[test]
+    fn from_case_matches_case() {
+        let spec = CaseSpec::from_case("column", Case::Snake);
+        assert_eq!(
+            "user_id".to_case(Case::Snake),
+            "user_id".to_case(spec.as_case())
+        );
+        assert_eq!("column", spec.to_string());
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(dunder(), dunder());
+        assert_ne!(dunder(), dunder().set_pattern(pattern::uppercase));
+        assert_ne!(dunder(), dunder().set_delim("--"));
+    }
+
+    #[test]
+    fn owns_runtime_pattern() {
+        let stop_words = [String::from("the"), String::from("of")];
+        let spec = dunder().set_pattern(move |words: &[&str]| {
+            words
+                .iter()
+                .filter(|w| !stop_words.iter().any(|s| s.eq_ignore_ascii_case(w)))
+                .map(|w| w.to_lowercase())
+                .collect()
+        });
+        let copy = spec.clone();
+        drop(spec);
+        assert_eq!("lord__rings", "TheLordOfTheRings".to_case(copy.as_case()));
+        assert_eq!(
+            "lord__rings",
+            copy.converter().convert("the lord of the rings")
+        );
+        assert_eq!(copy, copy.clone());
+        assert_ne!(copy, dunder());
+    }
+
+    #[test]
+    fn registered_names_shadow_builtins() {
+        let mut registry = CaseRegistry::new();
+        assert_eq!(Ok(Case::Snake), registry.parse("snake_case"));
+        registry.register(CaseSpec::from_case("Snake", Case::Kebab));
+        assert_eq!(Ok("-"), registry.parse("snake_case").map(|c| c.delim()));
+
+        registry.unregister("SNAKE");
+        assert_eq!(Ok(Case::Snake), registry.parse("snake_case"));
+        assert!(registry.specs().is_empty());
+    }
+
+    #[test]
+    fn parse_error_lists_registered() {
+        let registry: CaseRegistry = [dunder(), CaseSpec::new("Verbatim")].into_iter().collect();
+        let err = registry.parse("sponge").unwrap_err();
+        assert_eq!("sponge", err.input());
+        assert!(err.to_string().ends_with(", dunder, Verbatim"));
+        assert!(!"sponge"
+            .parse::<Case>()
+            .unwrap_err()
+            .to_string()
+            .contains("dunder"));
+    }
+
+    #[test]
+    fn detect_agrees_with_is_case() {
+        let registry: CaseRegistry = [dunder(), CaseSpec::from_case("column", Case::Snake)]
+            .into_iter()
+            .collect();
+        for s in [
+            "my__var", "MY__VAR", "my_var", "my", "My", "myVar", "my__Var",
+        ] {
+            let detection = registry.detect(s);
+            for spec in registry.specs() {
+                assert_eq!(
+                    s.is_case(spec.as_case()),
+                    detection.cases().any(|c| c == spec.as_case()),
+                    "{:?} in {}",
+                    s,
+                    spec
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn detect_prefers_explained_boundaries() {
+        let registry: CaseRegistry = [dunder()].into_iter().collect();
+        let detection = registry.detect("my__var");
+        assert_eq!(Some(registry.specs()[0].as_case()), detection.best());
+        assert!(!detection.is_ambiguous());
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn random_specs_not_detected() {
+        let registry: CaseRegistry = [CaseSpec::new("chaos").set_pattern(pattern::random)]
+            .into_iter()
+            .collect();
+        assert!(registry
+            .detect("x")
+            .cases()
+            .all(|c| !matches!(c, Case::Custom { .. })));
+    }
+}
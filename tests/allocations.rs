@@ -0,0 +1,112 @@
+use convert_case::{BorrowedConverter, Case, Casing, Converter, MakeCase};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+// Counted per thread, so allocations made by the test harness are not included
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static SNAKE: BorrowedConverter = BorrowedConverter::new().to_case(Case::Snake);
+
+#[test]
+fn only_converted_strings_allocate() {
+    let converters: Vec<Converter> = Case::deterministic_cases()
+        .iter()
+        .map(|&case| Converter::new().to_case(case))
+        .collect();
+    let mut buf = String::with_capacity(64);
+
+    let before = allocations();
+    for conv in &converters {
+        for s in ["XMLHttpRequest", "my_variable_name", "Straße 22 ǆungla"] {
+            buf.clear();
+            conv.convert_into(s, &mut buf).unwrap();
+        }
+    }
+    for &case in Case::deterministic_cases() {
+        buf.clear();
+        "XMLHttpRequest".to_case_into(case, &mut buf).unwrap();
+    }
+    buf.clear();
+    SNAKE.convert_into("XMLHttpRequest", &mut buf).unwrap();
+    assert_eq!(before, allocations());
+
+    // Only the converted string is allocated
+    assert_eq!("xml_http_request", "XMLHttpRequest".to_case(Case::Snake));
+    let before = allocations();
+    let snake = "My-Variable-Name".to_case(Case::Snake);
+    assert_eq!(before + 1, allocations());
+    assert_eq!("my_variable_name", snake);
+
+    // Strings already in the case are borrowed
+    for case in [
+        Case::Snake,
+        Case::Constant,
+        Case::Kebab,
+        Case::Camel,
+        Case::Pascal,
+        Case::Lower,
+        Case::Title,
+        Case::Dot,
+    ] {
+        let s = "XMLHttpRequest".to_case(case);
+        let before = allocations();
+        assert!(matches!(s.to_case_cow(case), Cow::Borrowed(_)));
+        assert_eq!(before, allocations(), "{:?}", case);
+    }
+}
+
+#[test]
+fn in_place_converts_once() {
+    let conv = Converter::new().to_case(Case::Snake);
+
+    // Strings already in the case are left alone
+    let mut s = String::from("my_variable_name");
+    let before = allocations();
+    assert!(!conv.convert_in_place(&mut s));
+    assert!(!s.make_case(Case::Snake));
+    assert_eq!(before, allocations());
+
+    // A conversion that fits is written into the existing buffer
+    let mut s = String::from("My-Variable-Name");
+    let before = allocations();
+    assert!(conv.convert_in_place(&mut s));
+    assert_eq!(before, allocations());
+    assert_eq!("my_variable_name", s);
+
+    let mut s = String::from("My-Variable-Name");
+    let before = allocations();
+    assert!(s.make_case(Case::Snake));
+    assert_eq!(before, allocations());
+    assert_eq!("my_variable_name", s);
+
+    // A conversion that does not fit only grows the buffer
+    let mut s = String::from("myVariableName");
+    let before = allocations();
+    assert!(conv.convert_in_place(&mut s));
+    assert_eq!(before + 1, allocations());
+    assert_eq!("my_variable_name", s);
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "serde")]
+
+use convert_case::{Boundary, Converter};
+
+// The boundary constants are compared by their conditions, which must hold
+// outside of the crate that defines them
+#[test]
+fn builtin_boundaries_serialize_by_name() {
+    for b in Boundary::defaults() {
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(format!("\"{}\"", b.name), json);
+    }
+    assert_eq!(
+        "\"UpperLower\"",
+        serde_json::to_string(&Boundary::UPPER_LOWER).unwrap()
+    );
+    assert!(serde_json::to_string(&Converter::new()).is_ok());
+}
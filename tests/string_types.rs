@@ -17,6 +17,7 @@ fn str_type() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn string_ref_type() {
     let s: String = String::from("rust_programming_language");
     assert_eq!("RustProgrammingLanguage", (&s).to_case(Case::Pascal),);